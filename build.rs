@@ -9,36 +9,99 @@ extern crate serde_derive;
 #[path="src/parser.rs"]
 mod parser;
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::io::{Read, Write};
 use std::thread::{self, JoinHandle};
 use std::{path, env, fs};
 use grammar::Lang;
 
-pub fn train_async(lang: Lang) -> JoinHandle<()> {
-    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", lang.to_string().to_lowercase());
-    thread::spawn(move || {
-        let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-        let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
-        let rules = grammar::rules(lang).unwrap();
-        let examples =  grammar::examples(lang);
-        let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
-        ::rmp_serde::encode::write(&mut file, &model).unwrap();
-    })
+/// Sources shared by all the languages, a change in one of them retrains every model.
+const COMMON_SOURCES: &'static [&'static str] = &["src/parser.rs", "values/src", "moment/src", "grammar/src"];
+
+/// Manifests and lock file pinning the versions of the crates used for training, like rustling
+/// or rmp_serde. The lock file may be missing, e.g. on a fresh checkout.
+const MANIFESTS: &'static [&'static str] = &["Cargo.toml", "Cargo.lock"];
+
+fn lang_sources(lang: Lang) -> String {
+    format!("grammar/{}/src", lang.to_string().to_lowercase())
+}
+
+fn hash_path(hasher: &mut DefaultHasher, path: &path::Path) {
+    if !path.exists() {
+        return;
+    }
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path).unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        entries.sort();
+        for entry in entries {
+            hash_path(hasher, &entry);
+        }
+    } else {
+        let mut content = vec![];
+        fs::File::open(path).unwrap().read_to_end(&mut content).unwrap();
+        hasher.write(path.to_string_lossy().as_bytes());
+        hasher.write(&content);
+    }
 }
 
-pub fn train_sync(lang: Lang) {
-    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", lang.to_string().to_lowercase());
+/// Hash of the sources of the rules and examples of a language, and of the versions of the
+/// crates training them.
+fn sources_hash(lang: Lang) -> String {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+    let sources = MANIFESTS.iter()
+        .chain(COMMON_SOURCES.iter())
+        .map(|s| s.to_string())
+        .chain(Some(lang_sources(lang)));
+    for source in sources {
+        hash_path(&mut hasher, path::Path::new(&source));
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn model_path(lang: Lang, extension: &str) -> path::PathBuf {
     let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-    let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
+    out_dir.join(format!("{}.{}", lang.to_string().to_lowercase(), extension))
+}
+
+/// Whether the model of a language was trained on the current sources.
+fn is_up_to_date(lang: Lang, hash: &str) -> bool {
+    let mut previous = String::new();
+    model_path(lang, "rmp").exists()
+        && fs::File::open(model_path(lang, "hash"))
+            .and_then(|mut file| file.read_to_string(&mut previous))
+            .is_ok()
+        && previous == hash
+}
+
+pub fn train(lang: Lang, hash: &str) {
+    let mut file = fs::File::create(model_path(lang, "rmp")).unwrap();
     let rules = grammar::rules(lang).unwrap();
     let examples =  grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
     ::rmp_serde::encode::write(&mut file, &model).unwrap();
+    // Written last, so that an interrupted training is not mistaken for an up to date model.
+    fs::File::create(model_path(lang, "hash")).unwrap().write_all(hash.as_bytes()).unwrap();
+}
+
+pub fn train_async(lang: Lang, hash: String) -> JoinHandle<()> {
+    thread::spawn(move || train(lang, &hash))
+}
+
+pub fn train_sync(lang: Lang, hash: String) {
+    train(lang, &hash)
 }
 
+/// Trains the models of the languages whose sources changed, in parallel.
 pub fn train_all_async() {
-    let join_handlers: Vec<_> = Lang::all().into_iter().map(|lang| {
-        train_async(lang)
-    }).collect();
+    let join_handlers: Vec<_> = Lang::all().into_iter()
+        .map(|lang| (lang, sources_hash(lang)))
+        .filter(|&(lang, ref hash)| !is_up_to_date(lang, hash))
+        .map(|(lang, hash)| train_async(lang, hash))
+        .collect();
 
     for join in join_handlers {
         join.join().unwrap();
@@ -47,10 +110,24 @@ pub fn train_all_async() {
 
 pub fn train_all_sync() {
     for lang in Lang::all() {
-        train_sync(lang);
+        let hash = sources_hash(lang);
+        if !is_up_to_date(lang, &hash) {
+            train_sync(lang, hash);
+        }
     }
 }
 
 fn main() {
-    train_all_sync();
+    for source in MANIFESTS.iter().chain(COMMON_SOURCES.iter()) {
+        println!("cargo:rerun-if-changed={}", source);
+    }
+    for lang in Lang::all() {
+        println!("cargo:rerun-if-changed={}", lang_sources(lang));
+    }
+    println!("cargo:rerun-if-env-changed=RUSTLING_TRAIN_SYNC");
+    if env::var("RUSTLING_TRAIN_SYNC").is_ok() {
+        train_all_sync();
+    } else {
+        train_all_async();
+    }
 }
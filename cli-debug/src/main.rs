@@ -14,16 +14,22 @@ use values::{ParsingContext, ResolverContext};
 fn main() {
     let matches = clap_app!(rustling_cli =>
         (@arg lang: -l --lang default_value[en] "2-letter language code (default to \"en\")")
+        (@arg reference: -r --reference +takes_value "Reference time, ISO 8601 with optional offset like 2013-02-12T04:30:00+01:00 (default to 2013-02-12T04:30:00)")
         (@subcommand parse =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg sentence: +required "Sentence to test")
         )
     ).get_matches();
     let lang = value_t!(matches.value_of("lang"), grammar::Lang).unwrap_or_else(|e| e.exit());
+    let reference = if matches.is_present("reference") {
+        value_t!(matches.value_of("reference"), Moment<Tz>).unwrap_or_else(|e| e.exit())
+    } else {
+        Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0))
+    };
     match matches.subcommand() {
         ("parse", Some(matches)) => {
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
-            let decoder = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+            let decoder = ResolverContext::new(Interval::starting_at(reference, Grain::Second));
             let rules = grammar::rules(lang).unwrap();
             let matches = rules.apply_all(&*sentence).unwrap();
             let mut table = Table::new();
@@ -3,15 +3,81 @@ extern crate clap;
 extern crate rustling_ontology;
 #[macro_use]
 extern crate prettytable;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 
+use std::fs;
+use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 
 use rustling_ontology::*;
+use rustling_ontology::output::OutputMatch;
 use prettytable::Table;
 
+/// Line of the batch input, when given as a JSON object.
+#[derive(Debug, Deserialize)]
+struct BatchInput {
+    text: String,
+    reference: Option<Moment<Tz>>,
+    kinds: Option<Vec<OutputKind>>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchOutput {
+    text: String,
+    matches: Vec<OutputMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl BatchOutput {
+    fn error(text: String, error: String) -> BatchOutput {
+        BatchOutput {
+            text: text,
+            matches: vec![],
+            error: Some(error),
+        }
+    }
+}
+
+fn parse_batch_line(parser: &Parser,
+                    line: &str,
+                    default_context: &ResolverContext,
+                    default_kinds: &[OutputKind])
+                    -> BatchOutput {
+    let input = if line.trim_left().starts_with('{') {
+        match serde_json::from_str::<BatchInput>(line) {
+            Ok(input) => input,
+            Err(e) => return BatchOutput::error(line.to_string(), format!("{}", e)),
+        }
+    } else {
+        BatchInput {
+            text: line.to_string(),
+            reference: None,
+            kinds: None,
+        }
+    };
+    let context = input.reference
+        .map(|reference| ResolverContext::new(Interval::starting_at(reference, Grain::Second)))
+        .unwrap_or_else(|| default_context.clone());
+    let kinds = input.kinds.unwrap_or_else(|| default_kinds.to_vec());
+    let sentence = input.text.to_lowercase();
+    match parser.parse_with_kind_order(&*sentence, &context, &kinds) {
+        Ok(entities) => BatchOutput {
+            text: input.text,
+            matches: entities.into_iter().map(OutputMatch::from).collect(),
+            error: None,
+        },
+        Err(e) => BatchOutput::error(input.text, format!("{}", e)),
+    }
+}
+
 fn main() {
     let matches = clap_app!(rustling_cli =>
         (@arg lang: -l --lang default_value[en] "2-letter language code (default to \"en\")")
+        (@arg reference: -r --reference +takes_value "Reference time, ISO 8601 with optional offset like 2013-02-12T04:30:00+01:00 (default to now)")
         (@subcommand parse =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg sentence: +required "Sentence to test")
@@ -20,10 +86,20 @@ fn main() {
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg sentence: +required "Sentence to test")
         )
+        (@subcommand batch =>
+             (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
+             (@arg input: "File with one sentence or JSON object per line (default to stdin)")
+        )
     )
         .get_matches();
 
     let lang = value_t!(matches.value_of("lang"), Lang).unwrap_or_else(|e| e.exit());
+    let context = if matches.is_present("reference") {
+        let reference = value_t!(matches.value_of("reference"), Moment<Tz>).unwrap_or_else(|e| e.exit());
+        ResolverContext::new(Interval::starting_at(reference, Grain::Second))
+    } else {
+        ResolverContext::default()
+    };
     match matches.subcommand() {
         ("parse", Some(matches)) => {
             let kinds = matches
@@ -35,7 +111,6 @@ fn main() {
                   });
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
             let parser = build_parser(lang).unwrap();
-            let context = ResolverContext::default();
             let entities = if let Some(kinds) = kinds {
                 parser.parse_with_kind_order(&*sentence, &context, &kinds).unwrap()
             } else {
@@ -71,8 +146,6 @@ fn main() {
                 .unwrap_or(OutputKind::all());
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
             let parser = build_raw_parser(lang).unwrap();
-            
-            let context = ResolverContext::default();
             let tagger = CandidateTagger {
                 order: &kinds,
                 context: &context,
@@ -113,6 +186,32 @@ fn main() {
             }
             table.printstd();
         }
+        ("batch", Some(matches)) => {
+            let kinds = matches
+                .values_of("kinds")
+                .map(|values| {
+                         values
+                             .map(|s| OutputKind::from_str(s).unwrap())
+                             .collect()
+                     })
+                .unwrap_or(OutputKind::all());
+            let parser = build_parser(lang).unwrap();
+            let input: Box<BufRead> = match matches.value_of("input") {
+                Some(path) => Box::new(io::BufReader::new(fs::File::open(path).unwrap())),
+                None => Box::new(io::BufReader::new(io::stdin())),
+            };
+            let stdout = io::stdout();
+            let mut stdout = stdout.lock();
+            for line in input.lines() {
+                let line = line.unwrap();
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let output = parse_batch_line(&parser, &line, &context, &kinds);
+                serde_json::to_writer(&mut stdout, &output).unwrap();
+                writeln!(stdout, "").unwrap();
+            }
+        }
         (cmd, _) => panic!("Unknown command {}", cmd),
     }
 }
@@ -0,0 +1,62 @@
+#ifndef RUSTLING_ONTOLOGY_H
+#define RUSTLING_ONTOLOGY_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ * Result code returned by every function of the bindings.
+ */
+typedef enum {
+  RUSTLING_RESULT_OK = 0,
+  RUSTLING_RESULT_KO = 1,
+} RUSTLING_RESULT;
+
+/*
+ * Opaque parser handle.
+ */
+typedef struct RustlingParser RustlingParser;
+
+/*
+ * Builds the parser of a language, given as a 2-letter code (e.g. "en").
+ *
+ * The handle must be released with `rustling_ontology_destroy_parser`.
+ */
+RUSTLING_RESULT rustling_ontology_create_parser(const char *lang, const RustlingParser **parser);
+
+/*
+ * Releases a parser built by `rustling_ontology_create_parser`.
+ */
+RUSTLING_RESULT rustling_ontology_destroy_parser(RustlingParser *parser);
+
+/*
+ * Releases a string returned by the bindings.
+ */
+RUSTLING_RESULT rustling_ontology_destroy_string(char *string);
+
+/*
+ * Writes the message of the last error raised on this thread in `error`. The message must be
+ * released with `rustling_ontology_destroy_string`.
+ */
+RUSTLING_RESULT rustling_ontology_get_last_error(const char **error);
+
+/*
+ * Parses `text` and writes the matches as a JSON array in `result`.
+ *
+ * `reference` is an RFC 3339 timestamp (e.g. "2013-02-12T04:30:00+01:00") used to resolve
+ * times, or null for the current local time. `kinds` is an array of `kinds_len` output kinds
+ * (e.g. "Time") given by decreasing priority, or null for all kinds. The result must be
+ * released with `rustling_ontology_destroy_string`.
+ */
+RUSTLING_RESULT rustling_ontology_parse(const RustlingParser *parser,
+                                        const char *text,
+                                        const char *reference,
+                                        const char *const *kinds,
+                                        uintptr_t kinds_len,
+                                        const char **result);
+
+#endif /* RUSTLING_ONTOLOGY_H */
@@ -0,0 +1,223 @@
+//! C bindings for the ontology.
+//!
+//! A parser is built once with `rustling_ontology_create_parser` and used through an opaque
+//! handle. Parsing results are returned as JSON strings, using the same format as the `batch`
+//! command of the cli. Every function returns a `RUSTLING_RESULT`; on failure, the error message
+//! can be retrieved with `rustling_ontology_get_last_error`.
+//!
+//! The C header lives in `include/rustling_ontology.h` and is generated with
+//! `cbindgen --config cbindgen.toml --output include/rustling_ontology.h`.
+extern crate rustling_ontology;
+extern crate serde_json;
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic;
+use std::slice;
+use std::str::FromStr;
+
+use rustling_ontology::*;
+use rustling_ontology::output::OutputMatch;
+
+/// Result code returned by every function of the bindings.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RUSTLING_RESULT {
+    RUSTLING_RESULT_OK = 0,
+    RUSTLING_RESULT_KO = 1,
+}
+
+/// Opaque parser handle.
+pub struct RustlingParser {
+    parser: Parser,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+fn describe_error(error: &Error) -> String {
+    error.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(": ")
+}
+
+fn wrap<F>(f: F) -> RUSTLING_RESULT
+    where F: FnOnce() -> Result<(), String>
+{
+    let error = match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return RUSTLING_RESULT::RUSTLING_RESULT_OK,
+        Ok(Err(e)) => e,
+        Err(_) => "Rustling panicked".to_string(),
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+    RUSTLING_RESULT::RUSTLING_RESULT_KO
+}
+
+unsafe fn str_arg<'a>(arg: *const c_char, name: &str) -> Result<&'a str, String> {
+    if arg.is_null() {
+        return Err(format!("{} must not be null", name));
+    }
+    CStr::from_ptr(arg).to_str().map_err(|_| format!("{} is not valid UTF-8", name))
+}
+
+fn into_raw_string(s: String) -> Result<*const c_char, String> {
+    CString::new(s)
+        .map(|s| s.into_raw() as *const c_char)
+        .map_err(|_| "Output contains a nul byte".to_string())
+}
+
+/// Builds the parser of a language, given as a 2-letter code (e.g. "en").
+///
+/// The handle must be released with `rustling_ontology_destroy_parser`.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_create_parser(lang: *const c_char,
+                                                         parser: *mut *const RustlingParser)
+                                                         -> RUSTLING_RESULT {
+    wrap(|| {
+        if parser.is_null() {
+            return Err("parser must not be null".to_string());
+        }
+        let lang = Lang::from_str(str_arg(lang, "lang")?)?;
+        let built = build_parser(lang).map_err(|e| describe_error(&e))?;
+        *parser = Box::into_raw(Box::new(RustlingParser { parser: built }));
+        Ok(())
+    })
+}
+
+/// Parses `text` and writes the matches as a JSON array in `result`.
+///
+/// `reference` is an RFC 3339 timestamp (e.g. "2013-02-12T04:30:00+01:00") used to resolve
+/// times, or null for the current local time. `kinds` is an array of `kinds_len` output kinds
+/// (e.g. "Time") given by decreasing priority, or null for all kinds. The result must be
+/// released with `rustling_ontology_destroy_string`.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_parse(parser: *const RustlingParser,
+                                                 text: *const c_char,
+                                                 reference: *const c_char,
+                                                 kinds: *const *const c_char,
+                                                 kinds_len: usize,
+                                                 result: *mut *const c_char)
+                                                 -> RUSTLING_RESULT {
+    wrap(|| {
+        if parser.is_null() || result.is_null() {
+            return Err("parser and result must not be null".to_string());
+        }
+        let text = str_arg(text, "text")?;
+        let context = if reference.is_null() {
+            ResolverContext::default()
+        } else {
+            let reference = Moment::<Tz>::from_str(str_arg(reference, "reference")?)?;
+            ResolverContext::new(Interval::starting_at(reference, Grain::Second))
+        };
+        let kinds = if kinds.is_null() {
+            OutputKind::all()
+        } else {
+            slice::from_raw_parts(kinds, kinds_len).iter()
+                .map(|kind| OutputKind::from_str(str_arg(*kind, "kind")?))
+                .collect::<Result<Vec<_>, String>>()?
+        };
+        let matches = (*parser).parser
+            .parse_with_kind_order(&*text.to_lowercase(), &context, &kinds)
+            .map_err(|e| describe_error(&e))?
+            .into_iter()
+            .map(OutputMatch::from)
+            .collect::<Vec<_>>();
+        let json = serde_json::to_string(&matches).map_err(|e| e.to_string())?;
+        *result = into_raw_string(json)?;
+        Ok(())
+    })
+}
+
+/// Writes the message of the last error raised on this thread in `error`. The message must be
+/// released with `rustling_ontology_destroy_string`.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_get_last_error(error: *mut *const c_char) -> RUSTLING_RESULT {
+    wrap(|| {
+        if error.is_null() {
+            return Err("error must not be null".to_string());
+        }
+        let message = LAST_ERROR.with(|last| last.borrow().clone())
+            .unwrap_or_else(|| "No error".to_string());
+        *error = into_raw_string(message)?;
+        Ok(())
+    })
+}
+
+/// Releases a parser built by `rustling_ontology_create_parser`.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_destroy_parser(parser: *mut RustlingParser) -> RUSTLING_RESULT {
+    wrap(|| {
+        if !parser.is_null() {
+            drop(Box::from_raw(parser));
+        }
+        Ok(())
+    })
+}
+
+/// Releases a string returned by the bindings.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_destroy_string(string: *mut c_char) -> RUSTLING_RESULT {
+    wrap(|| {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    unsafe fn take_string(s: *const c_char) -> String {
+        let owned = CStr::from_ptr(s).to_str().unwrap().to_string();
+        rustling_ontology_destroy_string(s as *mut c_char);
+        owned
+    }
+
+    unsafe fn last_error() -> String {
+        let mut error = ptr::null();
+        assert_eq!(RUSTLING_RESULT::RUSTLING_RESULT_OK, rustling_ontology_get_last_error(&mut error));
+        take_string(error)
+    }
+
+    #[test]
+    fn test_parse() {
+        unsafe {
+            let mut parser = ptr::null();
+            let lang = CString::new("en").unwrap();
+            assert_eq!(RUSTLING_RESULT::RUSTLING_RESULT_OK, rustling_ontology_create_parser(lang.as_ptr(), &mut parser));
+
+            let text = CString::new("tomorrow").unwrap();
+            let reference = CString::new("2013-02-12T04:30:00+01:00").unwrap();
+            let time = CString::new("Time").unwrap();
+            let kinds = [time.as_ptr()];
+            let mut result = ptr::null();
+            assert_eq!(RUSTLING_RESULT::RUSTLING_RESULT_OK,
+                       rustling_ontology_parse(parser, text.as_ptr(), reference.as_ptr(), kinds.as_ptr(), 1, &mut result));
+            let matches: Vec<OutputMatch> = serde_json::from_str(&take_string(result)).unwrap();
+            assert_eq!(1, matches.len());
+            assert_eq!(OutputKind::Time, matches[0].kind);
+
+            let unknown = CString::new("Foo").unwrap();
+            let kinds = [unknown.as_ptr()];
+            assert_eq!(RUSTLING_RESULT::RUSTLING_RESULT_KO,
+                       rustling_ontology_parse(parser, text.as_ptr(), ptr::null(), kinds.as_ptr(), 1, &mut result));
+            assert_eq!("Foo is not a known OutputKind", last_error());
+
+            rustling_ontology_destroy_parser(parser as *mut RustlingParser);
+        }
+    }
+
+    #[test]
+    fn test_unknown_lang() {
+        unsafe {
+            let mut parser = ptr::null();
+            let lang = CString::new("xx").unwrap();
+            assert_eq!(RUSTLING_RESULT::RUSTLING_RESULT_KO, rustling_ontology_create_parser(lang.as_ptr(), &mut parser));
+            assert_eq!("Unknown language xx", last_error());
+        }
+    }
+}
@@ -2,7 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period};
+use rustling_ontology_moment::{Grain, DateOrder, PartOfDay, Season, PeriodComp, Weekday, Period};
 
 fn german_article_regex() -> &'static str {
     r#"(?:i[nm]s?|zu[rm]?|beim?|um|w[äa]h?rend|f[uü]r) ?(?:de(?:r|m|s|n)|die|das)?"#
@@ -104,6 +104,56 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_2("unter <amount-of-money>",
+             b.reg(r#"unter|weniger als|billiger als|g[üu]nstiger als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("höchstens <amount-of-money>",
+             b.reg(r#"bis zu|h[öo]chstens|maximal|nicht mehr als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("über <amount-of-money>",
+             b.reg(r#"[üu]ber|mehr als|teurer als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("mindestens <amount-of-money>",
+             b.reg(r#"mindestens|wenigstens|nicht weniger als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("zwischen <amount-of-money> und <amount-of-money>",
+             b.reg(r#"zwischen"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"und"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> bis <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|bis"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_4("von <amount-of-money> bis <amount-of-money>",
+             b.reg(r#"von"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"-|bis"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
+    b.rule_2("<unit> <number range>",
+             money_unit!(),
+             number_range_check!(),
+             |a, b| helpers::money_range(b.value(), a.value())
+    );
     Ok(())
 }
 
@@ -1065,51 +1115,54 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  time.value().form_time_of_day()?.is_12_clock())
     );
     b.rule_1_terminal("dd/mm/yyyy",
-                      b.reg(r#"([012]?[1-9]|10|20|30|31)[\./](0?[1-9]|10|11|12)[\./](\d{2,4})"#)?,
-                      |text_match| helpers::ymd(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
+                      b.reg(r#"([012]?[1-9]|10|20|30|31)[\./]([012]?[1-9]|10|20|30|31)[\./](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayFirst,
                       )
     );
     b.rule_1_terminal("dd-mm-yyyy",
-                      b.reg(r#"([012]?[1-9]|10|20|30|31)-(0?[1-9]|10|11|12)-(\d{2,4})"#)?,
-                      |text_match| helpers::ymd(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
+                      b.reg(r#"([012]?[1-9]|10|20|30|31)-([012]?[1-9]|10|20|30|31)-(\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayFirst,
                       )
     );
     b.rule_1_terminal("mm.dd.",
                       b.reg(r#"([012]?[1-9]|10|20|30|31)\.(0?[1-9]|10|11|12)\."#)?,
-                      |text_match| helpers::month_day(
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
                           text_match.group(2).parse()?,
-                          text_match.group(1).parse()?)
+                          None,
+                          DateOrder::DayFirst)
     );
     b.rule_1_terminal("dd/mm",
                       b.reg(r#"(10|20|30|31|[012]?[1-9])[/\.](10|11|12|0?[1-9])"#)?,
-                      |text_match| helpers::month_day(
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
                           text_match.group(2).parse()?,
-                          text_match.group(1).parse()?)
+                          None,
+                          DateOrder::DayFirst)
     );
     b.rule_1_terminal("breakfast (latent)",
                       b.reg(r#"fr[üu]hst[üu]ck(?:szeit|spause)?"#)?,
-                      |_| Ok(helpers::hour(6, false)?
-                          .span_to(&helpers::hour(9, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Breakfast, 6, 9)?
                           .latent()
                           .form(Form::Meal))
     );
     b.rule_1("lunch (latent)",
              b.reg(r#"mittag(?:szeit|pause|essen(?:szeit))"#)?,
-             |_| Ok(helpers::hour(12, false)?
-                 .span_to(&helpers::hour(14, false)?, false)?
+             |_| Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                  .latent()
                  .form(Form::Meal))
     );
     b.rule_1_terminal("lunch",
                       b.reg(r#"mittags"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                           .form(Form::Meal))
     );
     b.rule_1_terminal("dinner",
@@ -1127,8 +1180,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"morgens|in der fr[üu]h|vor ?mittag(?:s(?:zeit)?)?|am morgen"#)?,
-                      |_| Ok(helpers::hour(3, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Morning, 3, 12)?
                           .form(Form::PartOfDay(PartOfDayForm::Morning)))
     );
     b.rule_1_terminal("late morning",
@@ -1152,8 +1204,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"nach ?mittags?"#)?,
-                      |_| Ok(helpers::hour(13, false)?
-                          .span_to(&helpers::hour(19, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Afternoon, 13, 19)?
                           .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
     );
     b.rule_1_terminal("late afternoon (latent)",
@@ -1178,15 +1229,13 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("evening (latent)",
                       b.reg(r#"abend"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                           .latent()
                           .form(Form::PartOfDay(PartOfDayForm::Evening)))
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"abends"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                           .form(Form::PartOfDay(PartOfDayForm::Evening)))
     );
     b.rule_1_terminal("late evening (latent)",
@@ -1218,15 +1267,13 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("night (latent)",
                       b.reg(r#"nacht"#)?,
-                      |_| Ok(helpers::hour(0, false)?
-                          .span_to(&helpers::hour(4, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Night, 0, 4)?
                           .latent()
                           .form(Form::PartOfDay(PartOfDayForm::Night)))
     );
     b.rule_1_terminal("night",
                       b.reg(r#"nachts"#)?,
-                      |_| Ok(helpers::hour(0, false)?
-                          .span_to(&helpers::hour(4, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Night, 0, 4)?
                           .form(Form::PartOfDay(PartOfDayForm::Night)))
     );
     b.rule_1_terminal("late night",
@@ -1392,8 +1439,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"sommer(?:zeit|s)?"#)?,
-                      |_| Ok(helpers::month_day(6, 21)?
-                          .span_to(&helpers::month_day(9, 23)?, false)?
+                      |_| Ok(helpers::season(Season::Summer)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("Summer solstice",
@@ -1402,14 +1448,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"herbst(?:zeit|s|es)?|sp[äa]tjahr(?:es)?"#)?,
-                      |_| Ok(helpers::month_day(9, 23)?
-                          .span_to(&helpers::month_day(12, 21)?, false)?
+                      |_| Ok(helpers::season(Season::Autumn)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("season",
                       b.reg(r#"winter(?:zeit|s)?"#)?,
-                      |_| Ok(helpers::month_day(12, 21)?
-                          .span_to(&helpers::month_day(3, 20)?, false)?
+                      |_| Ok(helpers::season(Season::Winter)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("Winter solstice",
@@ -1418,8 +1462,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:fr[üu]hlings?|fr[üu]hjahr(?:es)?)(?:zeit)?"#)?,
-                      |_| Ok(helpers::month_day(3, 20)?
-                          .span_to(&helpers::month_day(6, 21)?, false)?
+                      |_| Ok(helpers::season(Season::Spring)?
                           .form(Form::PartOfYear))
     );
     b.rule_2("im <part-of-year>",
@@ -1720,20 +1763,44 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"an"#)?,
              |_, time, _| Ok(time.value().clone().mark_after_start().not_latent())
     );
+    b.rule_1("next business day",
+             b.reg(r#"(?:(?:am|der|den) )?n[äa]chste(?:r|n|m)? (?:werk|arbeits|gesch[äa]fts)tag"#)?,
+             |_| helpers::business_day_nth(1)
+    );
+    b.rule_1("previous business day",
+             b.reg(r#"(?:(?:am|der|den) )?(?:vorherige|vorige|letzte)(?:r|n|m)? (?:werk|arbeits|gesch[äa]fts)tag"#)?,
+             |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("in <integer> business days",
+             b.reg(r#"in|binnen|innerhalb von"#)?,
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(?:werk|arbeits|gesch[äa]fts)tage?n?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("<integer> business days ago",
+             b.reg(r#"vor"#)?,
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(?:werk|arbeits|gesch[äa]fts)tage?n?"#)?,
+             |_, integer, _| helpers::business_day_nth(-integer.value().value)
+    );
+    b.rule_2("first business day of <time>",
+             b.reg(r#"(?:(?:am|der|den) )?erste(?:r|n|m)? (?:werk|arbeits|gesch[äa]fts)tag (?:im|des|in der|vom)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_nth_after(0, time.value())
+    );
+    b.rule_2("last business day of <time>",
+             b.reg(r#"(?:(?:am|der|den) )?letzte(?:r|n|m)? (?:werk|arbeits|gesch[äa]fts)tag (?:im|des|in der|vom)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_last_of(time.value())
+    );
+    b.rule_1("last business day of the month",
+             b.reg(r#"(?:(?:am|der|den) )?letzte(?:r|n|m)? (?:werk|arbeits|gesch[äa]fts)tag (?:des|dieses) monats"#)?,
+             |_| helpers::business_day_last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
     Ok(())
 }
 
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
-    // TODO
-    // b.rule_2("under <temp>",
-    //     b.reg(r#"bis(?: zu)?|unter|weniger als"#)?,
-    //     temperature_check!(|temp: &TemperatureValue| !temp.latent),
-    // );
-    // b.rule_2("above <temp>",
-    //     b.reg(r#"[uü]ber|mehr als"#)?,
-    //     temperature_check!(|temp: &TemperatureValue| !temp.latent),
-    // );
-
     b.rule_2("<article> temp",
              b.reg(r#"bei"#)?,
              temperature_check!(),
@@ -1741,6 +1808,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: temp.value().unit,
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_1("number as temp",
@@ -1749,6 +1817,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value(),
                  unit: None,
                  latent: true,
+                 delta: false,
              })
     );
     b.rule_2("below <temp>",
@@ -1760,6 +1829,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: temp.value().latent,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -1775,6 +1845,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -1790,6 +1861,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -1805,6 +1877,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -1818,6 +1891,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("degree"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> celsius",
@@ -1827,6 +1901,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("celsius"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> kelvin",
@@ -1836,6 +1911,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("kelvin"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> fahrenheit",
@@ -1845,6 +1921,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> °F",
@@ -1854,8 +1931,290 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 delta: false,
              })
     );
+    b.rule_4("zwischen <temp> und <temp>",
+             b.reg(r#"zwischen"#)?,
+             temperature_check!(),
+             b.reg(r#"und"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp> bis <temp>",
+             temperature_check!(),
+             b.reg(r#"-|bis"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_4("von <temp> bis <temp>",
+             b.reg(r#"von"#)?,
+             temperature_check!(),
+             b.reg(r#"-|bis"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("über <temp>",
+             b.reg(r#"[üu]ber|mehr als|w[äa]rmer als"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("mindestens <temp>",
+             b.reg(r#"mindestens"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("unter <temp>",
+             b.reg(r#"unter|weniger als|k[äa]lter als"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("höchstens <temp>",
+             b.reg(r#"bis(?: zu)?|h[öo]chstens"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp> wärmer",
+             temperature_check!(),
+             b.reg(r#"w[äa]rmer|h[öo]her|mehr"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp> kälter",
+             temperature_check!(),
+             b.reg(r#"k[äa]lter|k[üu]hler|niedriger|weniger"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"k(?:ilo)?m(?:eter[ns]?)?|kms"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"m|meter[ns]?"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"cm|zentimeter[ns]?"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"mm|millimeter[ns]?"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> Meilen",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"meilen?"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> Fuß",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"fu(?:ß|ss)"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> Zoll",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"zoll"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_3("<distance> and <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"und"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, _, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"(?:ungef[äa]hr|etwa|circa|ca\.?|zirka|rund|fast)"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"genau|exakt"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("kilogram",
+                      b.reg(r#"kg|kilo(?:gramm)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "kilogram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("gram",
+                      b.reg(r#"g|gramm"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("milligram",
+                      b.reg(r#"mg|milligramm"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "milligram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("pound",
+                      b.reg(r#"pfund"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pound", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("ounce",
+                      b.reg(r#"unzen?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "ounce", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("litre",
+                      b.reg(r#"l|liter[ns]?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "litre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("centilitre",
+                      b.reg(r#"cl|zentiliter[ns]?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "centilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("millilitre",
+                      b.reg(r#"ml|milliliter[ns]?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "millilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tassen?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "cup", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("tablespoon",
+                      b.reg(r#"essl[öo]ffel[ns]?|el"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "tablespoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("teaspoon",
+                      b.reg(r#"teel[öo]ffel[ns]?|tl"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "teaspoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("pint",
+                      b.reg(r#"pints?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pint", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("gallon",
+                      b.reg(r#"gallonen?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gallon", family: QuantityFamily::Volume })
+    );
+    b.rule_2("<number> <unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, b| helpers::quantity(a.value().value(), b.value())
+    );
+    b.rule_2("<fraction> <unit>",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| helpers::quantity(helpers::fraction(text_match.group(1), text_match.group(2))?, a.value())
+    );
+    b.rule_2("<integer> <fraction> <unit>",
+             b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let integer: f32 = text_match.group(1).parse()?;
+                 helpers::quantity(integer + helpers::fraction(text_match.group(2), text_match.group(3))?, a.value())
+             });
+    b.rule_2("<fraction> of a <unit>",
+             b.reg(r#"(?:eine?[nrms]? )?(halb|viertel|drittel|dreiviertel|drei viertel|zwei drittel)(?:e[nrs]?)?"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let value = match text_match.group(1).as_ref() {
+                     "halb" => 0.5,
+                     "viertel" => 0.25,
+                     "drittel" => 1.0 / 3.0,
+                     "dreiviertel" => 0.75,
+                     "drei viertel" => 0.75,
+                     "zwei drittel" => 2.0 / 3.0,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::quantity(value, a.value())
+             });
+    b.rule_2("1..12 and a half <unit>",
+             b.reg(r#"(ein|zwei|drei|vier|f[üu]nf|sechs|sieben|acht|neun|zehn|elf|zw[öo]lf)einhalb"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let value = match text_match.group(1).as_ref() {
+                     "ein" => 1,
+                     "zwei" => 2,
+                     "drei" => 3,
+                     "vier" => 4,
+                     "funf" => 5,
+                     "fünf" => 5,
+                     "sechs" => 6,
+                     "sieben" => 7,
+                     "acht" => 8,
+                     "neun" => 9,
+                     "zehn" => 10,
+                     "elf" => 11,
+                     "zwolf" => 12,
+                     "zwölf" => 12,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::quantity(value as f32 + 0.5, a.value())
+             });
+    b.rule_2("one and a half <unit>",
+             b.reg(r#"anderthalb"#)?,
+             quantity_unit!(),
+             |_, a| helpers::quantity(1.5, a.value())
+    );
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:ungef[äa]hr|etwa|circa|ca\.?|zirka|rund|fast)"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"genau|exakt"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"von (\w+)"#)?,
+             |a, text_match| {
+                 Ok(QuantityValue {
+                     product: Some(text_match.group(1).to_string()),
+                     ..a.value().clone()
+                 })
+             });
+    // Without "von", only common products are read, "2 kilo für 3 euro" has no product
+    b.rule_2("<quantity> <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"(äpfel|birnen|bananen|kartoffeln|tomaten|zwiebeln|karotten|erdbeeren|kirschen|trauben|mehl|zucker|salz|reis|nudeln|butter|käse|fleisch|hackfleisch|milch|sahne|wasser|saft|wein|bier|öl)"#)?,
+             |a, text_match| {
+                 Ok(QuantityValue {
+                     product: Some(text_match.group(1).to_string()),
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -2148,5 +2507,45 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              ordinal_check!(|ordinal: &OrdinalValue| ordinal.value % 10 == 0),
              |integer, _, ordinal| Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
     );
+    b.rule_4("zwischen <number> und <number>",
+             b.reg(r#"zwischen"#)?,
+             number_check!(),
+             b.reg(r#"und"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number> bis <number>",
+             number_check!(),
+             b.reg(r#"-|bis"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_4("von <number> bis <number>",
+             b.reg(r#"von"#)?,
+             number_check!(),
+             b.reg(r#"-|bis"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("mindestens <number>",
+             b.reg(r#"mindestens|wenigstens|nicht weniger als"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("mehr als <number>",
+             b.reg(r#"mehr als|[üu]ber|gr[öo](?:ß|ss)er als"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("bis zu <number>",
+             b.reg(r#"bis zu|h[öo]chstens|maximal|nicht mehr als"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("weniger als <number>",
+             b.reg(r#"weniger als|unter|kleiner als"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), false)
+    );
     Ok(())
 }
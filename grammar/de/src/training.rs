@@ -3,6 +3,25 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5 km", "fünf kilometer");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "drei meilen");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200 meter", "200 m");
+    example!(v, check_distance(180.0, Some("centimetre"), Precision::Exact), "1 m 80 cm", "1 meter und 80 zentimeter");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "ungefähr 10 km", "etwa zehn kilometer");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, Some("kilogram"), Precision::Exact, None), "2 kg", "zwei kilo");
+    example!(v, check_quantity(500.0, Some("gram"), Precision::Exact, None), "500 g", "500 gramm");
+    example!(v, check_quantity(0.5, Some("litre"), Precision::Exact, None), "ein halber liter", "1/2 liter");
+    example!(v, check_quantity(2.5, Some("kilogram"), Precision::Exact, None), "zweieinhalb kilo");
+    example!(v, check_quantity(1.5, Some("litre"), Precision::Exact, None), "anderthalb liter");
+    example!(v, check_quantity(1.0, Some("teaspoon"), Precision::Exact, None), "ein teelöffel", "1 tl");
+    example!(v, check_quantity(1.0, Some("kilogram"), Precision::Exact, Some("äpfel")), "ein kilo äpfel");
+    example!(v, check_quantity(3.0, Some("cup"), Precision::Approximate, None), "etwa 3 tassen", "ungefähr drei tassen");
+}
+
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(10.0, Some("degree")), "10 Grad");
     example!(v, check_temperature(-20.0, None), "minus 20");
@@ -24,6 +43,14 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(78.0, Some("fahrenheit")), "78 fahrenheit", "78 f");
     example!(v, check_temperature(19.0, Some("degree")), "19 grad über null");
     example!(v, check_temperature(-18.0, Some("degree")), "Bei -18 Grad");
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "zwischen 18 und 21 grad", "18 bis 21 grad", "von 18 bis 21 grad");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "unter 5 grad");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("degree")), "über 30 grad");
+    example!(v, check_temperature_interval(Some(20.0), None, true, false, Some("degree")), "mindestens 20 grad");
+    example!(v, check_temperature_interval(None, Some(25.0), false, true, Some("degree")), "höchstens 25 grad");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300 kelvin");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "zwei grad wärmer");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "drei grad kälter");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -49,10 +76,13 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(230983.0, Some("£"), Precision::Approximate), "beinahe 230983 £");
     example!(v, check_finance(150.0, Some("EUR"), Precision::Approximate), "nahezu hundertfünfzig Euro");
     example!(v, check_finance(100.0, Some("INR"), Precision::Exact), "sehr genau hundert indische Rupien");
+    example!(v, check_finance_interval(None, Some(50.0), Some("EUR")), "unter 50 euro", "weniger als 50 €", "höchstens fünfzig euro");
+    example!(v, check_finance_interval(Some(100.0), None, Some("$")), "über 100 dollar", "mehr als hundert dollar");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("EUR")), "zwischen 20 und 30 euro", "zwischen 20 euro und 30 euro", "20-30 euro", "von 20 bis 30 euro");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "jetzt", "genau jetzt", "gerade eben");
     example!(v, check_moment!(c, [2013, 2, 12]), "heute", "zu dieser zeit");
     example!(v, check_moment!(c, [2013, 2, 11]), "gestern");
@@ -106,6 +136,13 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 10]), "vorgestern");
     example!(v, check_moment!(c, [2013, 3, 25]), "letzter montag im märz");
     example!(v, check_moment!(c, [2014, 3, 30]), "letzter sonntag im märz 2014");
+    example!(v, check_moment!(c, [2013, 2, 13]), "nächster werktag", "am nächsten arbeitstag");
+    example!(v, check_moment!(c, [2013, 2, 11]), "vorheriger werktag");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 werktagen", "in drei arbeitstagen");
+    example!(v, check_moment!(c, [2013, 2, 8]), "vor 2 werktagen");
+    example!(v, check_moment!(c, [2013, 2, 28]), "letzter werktag des monats", "am letzten arbeitstag des monats");
+    example!(v, check_moment!(c, [2013, 3, 29]), "letzter werktag im märz");
+    example!(v, check_moment!(c, [2013, 3, 1]), "erster werktag im märz");
     example!(v, check_moment!(c, [2013, 10, 3]), "dritter tag im oktober");
     example!(v, check_moment!(c, [2014, 10, 6], Grain::Week), "erste woche im oktober 2014");
     example!(v, check_moment!(c, [2015, 10, 31]), "letzter tag im oktober 2015");
@@ -274,4 +311,10 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1000000.0), "1.000.000,00");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "zwischen 3 und 5", "von 3 bis 5", "3 bis 5");
+    example!(v, check_number_range(Some(10.0), Some(20.0), true, true), "10-20", "zwischen zehn und zwanzig");
+    example!(v, check_number_range(Some(5.0), None, true, false), "mindestens 5", "mindestens fünf");
+    example!(v, check_number_range(Some(5.0), None, false, false), "mehr als 5");
+    example!(v, check_number_range(None, Some(10.0), false, true), "bis zu 10", "höchstens zehn");
+    example!(v, check_number_range(None, Some(10.0), false, false), "weniger als 10");
 }
\ No newline at end of file
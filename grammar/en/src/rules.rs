@@ -4,7 +4,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, DateOrder, PartOfDay, Season, PeriodComp, Period};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -1037,11 +1037,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |_, a| helpers::hour_relative_minute(a.value().form_time_of_day()?.full_hour(), 30, true)
     );
     b.rule_1_terminal("mm/.dd/.yyyy",
-                      b.reg(r#"(0?[1-9]|1[0-2])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
-                      |text_match| helpers::ymd(
-                          text_match.group(3).parse()?,
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
-                          text_match.group(2).parse()?)
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::MonthFirst)
     );
     b.rule_1_terminal("yyyy-mm-dd",
                       b.reg(r#"(\d{2,4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
@@ -1052,24 +1053,24 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_1_terminal("mm/dd",
-                      b.reg(r#"(0?[1-9]|1[0-2])/(3[01]|[12]\d|0?[1-9])"#)?,
-                      |text_match| helpers::month_day(
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])/(3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
-                          text_match.group(2).parse()?)
+                          text_match.group(2).parse()?,
+                          None,
+                          DateOrder::MonthFirst)
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"morning"#)?,
                       |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(12, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Morning)))
                       }
     );
     b.rule_1_terminal("breakfast",
         b.reg(r#"breakfast"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Breakfast, 5, 10)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -1101,43 +1102,38 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("afternoon",
                       b.reg(r#"after ?noo?n"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(19, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 19)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
                       }
     );
-    b.rule_1_terminal("night",
-                      b.reg(r#"evening|night"#)?,
+    b.rule_1_terminal("evening",
+                      b.reg(r#"evening"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Evening)))
                       }
     );
 
     b.rule_1_terminal("night",
-                      b.reg(r#"evening|night"#)?,
+                      b.reg(r#"night"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Night, 18, 0)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Night)))
                       }
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"brunch"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Brunch, 10, 15)?
                 .latent()
                 .form(Form::Meal))
     );
     b.rule_1_terminal("lunch",
                       b.reg(r#"lunch"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(14, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                               .latent()
                               .form(Form::Meal))
                       }
@@ -1145,8 +1141,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("dinner",
         b.reg(r#"dinner|supper"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Dinner, 18, 23)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -1187,7 +1182,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("tonight",
                       b.reg(r#"toni(?:ght|gth|te)"#)?,
                       |_| {
-                          let period = helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?;
+                          let period = helpers::part_of_day(PartOfDay::Night, 18, 0)?;
                           Ok(helpers::cycle_nth(Grain::Day, 0)?
                               .intersect(&period)?
                               .form(Form::PartOfDay(PartOfDayForm::Night)))
@@ -1239,19 +1234,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"summer"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"fall|autumn"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"winter"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"spring"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("<time-of-day> approximately",
              time_check!(form!(Form::TimeOfDay(_))),
@@ -1387,6 +1382,44 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              time_check!(),
              |_, a| Ok(a.value().the_nth(-1)?.mark_after_start())
     );
+    b.rule_1("next business day",
+             b.reg(r#"(?:the )?(?:next|following) (?:business|working|work) ?day"#)?,
+             |_| helpers::business_day_nth(1)
+    );
+    b.rule_1("previous business day",
+             b.reg(r#"(?:the )?(?:previous|last) (?:business|working|work) ?day"#)?,
+             |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("in <integer> business days",
+             b.reg(r#"in|within"#)?,
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(?:business|working|work) ?days?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_2("<integer> business days from now",
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(?:business|working|work) ?days? (?:from (?:now|today)|later)"#)?,
+             |integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_2("<integer> business days ago",
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(?:business|working|work) ?days? ago"#)?,
+             |integer, _| helpers::business_day_nth(-integer.value().value)
+    );
+    b.rule_2("first business day of <time>",
+             b.reg(r#"(?:the )?first (?:business|working|work) ?day (?:of|in)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_nth_after(0, time.value())
+    );
+    b.rule_2("last business day of <time>",
+             b.reg(r#"(?:the )?last (?:business|working|work) ?day (?:of|in)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_last_of(time.value())
+    );
+    b.rule_1("last business day of the month",
+             b.reg(r#"(?:the )?last (?:business|working|work) ?day of (?:the|this) month"#)?,
+             |_| helpers::business_day_last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
     Ok(())
 }
 
@@ -1483,6 +1516,56 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_2("under <amount-of-money>",
+             b.reg(r#"under|below|less than|cheaper than"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"up to|at most|no more than|max(?:imum)?"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("over <amount-of-money>",
+             b.reg(r#"over|above|more than"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"at least|no less than|min(?:imum)?"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"between"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"and"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> to <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|to"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_4("from <amount-of-money> to <amount-of-money>",
+             b.reg(r#"from"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"-|to"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
+    b.rule_2("<unit> <number range>",
+             money_unit!(),
+             number_range_check!(),
+             |a, b| helpers::money_range(b.value(), a.value())
+    );
     Ok(())
 }
 
@@ -1494,6 +1577,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     delta: false,
                  })
              });
     b.rule_2("<latent temp> degrees",
@@ -1504,6 +1588,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -1514,6 +1599,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -1524,6 +1610,307 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"kelvins?|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_2("<temp> below zero",
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0 && !temp.delta),
+             b.reg(r#"below (?:zero|0)"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_4("between <temp> and <temp>",
+             b.reg(r#"between"#)?,
+             temperature_check!(),
+             b.reg(r#"and"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp> to <temp>",
+             temperature_check!(),
+             b.reg(r#"-|to"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_4("from <temp> to <temp>",
+             b.reg(r#"from"#)?,
+             temperature_check!(),
+             b.reg(r#"-|to"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("above <temp>",
+             b.reg(r#"above|over|more than|warmer than|hotter than"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("at least <temp>",
+             b.reg(r#"at least"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("below <temp>",
+             b.reg(r#"below|under|less than|colder than|cooler than"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("at most <temp>",
+             b.reg(r#"at most"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp> warmer",
+             temperature_check!(),
+             b.reg(r#"warmer|hotter|higher"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp> colder",
+             temperature_check!(),
+             b.reg(r#"colder|cooler|lower"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"k(?:ilo)?m(?:et(?:er|re)s?)?|kms"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"m|met(?:er|re)s?"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"cm|centimet(?:er|re)s?"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"mm|millimet(?:er|re)s?"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> miles",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"mi(?:le)?s?"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> yards",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"y(?:ar)?ds?"#)?,
+             |a, _| Ok(a.value().unit("yard"))
+    );
+    b.rule_2("<latent distance> feet",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"ft|foot|feet"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> inches",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"inch(?:es)?"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_3("<distance in feet> <number> in",
+             distance_check!(|distance: &DistanceValue| distance.unit == Some("foot")),
+             number_check!(),
+             b.reg(r#"in\.?"#)?,
+             |a, b, _| {
+                 helpers::compose_distances(a.value(), &DistanceValue {
+                     value: b.value().value(),
+                     unit: Some("inch"),
+                     precision: Exact,
+                     latent: false,
+                 })
+             });
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_3("<distance> and <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"and"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, _, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"(?:about|approx(?:\.|imately)?|close to|near(?: to)?|around|almost|roughly)"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"exactly|precisely"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("kilogram",
+                      b.reg(r#"kg|kilo(?:gram(?:me)?)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "kilogram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("gram",
+                      b.reg(r#"g|gr|gram(?:me)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("milligram",
+                      b.reg(r#"mg|milligram(?:me)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "milligram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("pound",
+                      b.reg(r#"lbs?|pounds?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pound", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("ounce",
+                      b.reg(r#"oz|ounces?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "ounce", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("litre",
+                      b.reg(r#"l|lit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "litre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("centilitre",
+                      b.reg(r#"cl|centilit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "centilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("millilitre",
+                      b.reg(r#"ml|millilit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "millilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"cups?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "cup", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("tablespoon",
+                      b.reg(r#"tbsp\.?|tablespoons?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "tablespoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("teaspoon",
+                      b.reg(r#"tsp\.?|teaspoons?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "teaspoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("pint",
+                      b.reg(r#"pints?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pint", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("gallon",
+                      b.reg(r#"gal(?:lon)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gallon", family: QuantityFamily::Volume })
+    );
+    b.rule_2("<number> <unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, b| helpers::quantity(a.value().value(), b.value())
+    );
+    b.rule_2("a <unit>",
+             b.reg(r#"an?"#)?,
+             quantity_unit!(),
+             |_, a| helpers::quantity(1.0, a.value())
+    );
+    b.rule_2("<fraction> <unit>",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| helpers::quantity(helpers::fraction(text_match.group(1), text_match.group(2))?, a.value())
+    );
+    b.rule_2("<integer> <fraction> <unit>",
+             b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let integer: f32 = text_match.group(1).parse()?;
+                 helpers::quantity(integer + helpers::fraction(text_match.group(2), text_match.group(3))?, a.value())
+             });
+    b.rule_2("<fraction> of a <unit>",
+             b.reg(r#"(?:an? |one )?(half|quarter|third|three quarters|two thirds)(?: of)?(?: an?)?"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let value = match text_match.group(1).as_ref() {
+                     "half" => 0.5,
+                     "quarter" => 0.25,
+                     "third" => 1.0 / 3.0,
+                     "three quarters" => 0.75,
+                     "two thirds" => 2.0 / 3.0,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::quantity(value, a.value())
+             });
+    b.rule_3("<integer> and a half <unit>",
+             integer_check!(|integer: &IntegerValue| integer.value >= 0),
+             b.reg(r#"and (?:an? )?half"#)?,
+             quantity_unit!(),
+             |a, _, b| helpers::quantity(a.value().value as f32 + 0.5, b.value())
+    );
+    b.rule_2("<quantity> and a half",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"and (?:an? )?half"#)?,
+             |a, _| {
+                 Ok(QuantityValue {
+                     value: a.value().value + 0.5,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:about|approx(?:\.|imately)?|close to|near(?: to)?|around|almost|roughly)"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"exactly|precisely"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"of (\w+)"#)?,
+             |a, text_match| {
+                 Ok(QuantityValue {
+                     product: Some(text_match.group(1).to_string()),
+                     ..a.value().clone()
                  })
              });
     Ok(())
@@ -1872,5 +2259,125 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"the"#)?,
              ordinal_check!(),
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"between"#)?,
+             number_check!(),
+             b.reg(r#"and"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number> to <number>",
+             number_check!(),
+             b.reg(r#"-|to|through"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_4("from <number> to <number>",
+             b.reg(r#"from"#)?,
+             number_check!(),
+             b.reg(r#"-|to|through"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("at least <number>",
+             b.reg(r#"at least|(?:a )?minimum(?: of)?|no (?:less|fewer) than"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("more than <number>",
+             b.reg(r#"more than|over|above|greater than"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("up to <number>",
+             b.reg(r#"up to|at most|(?:a )?maximum(?: of)?|no more than"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("less than <number>",
+             b.reg(r#"less than|fewer than|under|below"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), false)
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("every <cycle>",
+             b.reg(r#"every|each"#)?,
+             cycle_check!(),
+             |_, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain))
+    );
+    b.rule_1_terminal("everyday",
+                      b.reg(r#"everyday|daily"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Day))
+    );
+    b.rule_1_terminal("hourly",
+                      b.reg(r#"hourly"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Hour))
+    );
+    b.rule_1_terminal("weekly",
+                      b.reg(r#"weekly"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Week))
+    );
+    b.rule_1_terminal("monthly",
+                      b.reg(r#"monthly"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Month))
+    );
+    b.rule_1_terminal("yearly",
+                      b.reg(r#"yearly|annually"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Year))
+    );
+    b.rule_2("every other <cycle>",
+             b.reg(r#"every (?:other|second)"#)?,
+             cycle_check!(),
+             |_, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain).interval(2))
+    );
+    b.rule_3("every <integer> <cycle>",
+             b.reg(r#"every"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, integer, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain).interval(integer.value().value))
+    );
+    b.rule_2("every <time>",
+             b.reg(r#"every|each"#)?,
+             time_check!(|time: &TimeValue| time.direction.is_none()),
+             |_, time| Ok(RecurrenceValue::every_time(time.value().clone()))
+    );
+    b.rule_2("every other <time>",
+             b.reg(r#"every (?:other|second)"#)?,
+             time_check!(|time: &TimeValue| time.direction.is_none()),
+             |_, time| Ok(RecurrenceValue::every_time(time.value().clone()).interval(2))
+    );
+    b.rule_2("once|twice|thrice a <cycle>",
+             b.reg(r#"(once|twice|thrice) (?:a|an|per|every)"#)?,
+             cycle_check!(),
+             |text_match, cycle| {
+                 if text_match.group(1) == "once" {
+                     Ok(RecurrenceValue::every_cycle(cycle.value().grain))
+                 } else if text_match.group(1) == "twice" {
+                     Ok(RecurrenceValue::times_per_cycle(2, cycle.value().grain))
+                 } else {
+                     Ok(RecurrenceValue::times_per_cycle(3, cycle.value().grain))
+                 }
+             }
+    );
+    b.rule_3("<integer> times a <cycle>",
+             integer_check_by_range!(2),
+             b.reg(r#"times (?:a|an|per|every)"#)?,
+             cycle_check!(),
+             |integer, _, cycle| Ok(RecurrenceValue::times_per_cycle(integer.value().value, cycle.value().grain))
+    );
+    b.rule_2("<recurrence> <time>",
+             recurrence_check!(),
+             time_check!(),
+             |recurrence, time| recurrence.value().at(time.value())
+    );
+    b.rule_3("<recurrence> until <time>",
+             recurrence_check!(),
+             b.reg(r#"(?:un)?til(?:l)?|through"#)?,
+             time_check!(),
+             |recurrence, _, time| recurrence.value().until(time.value())
+    );
     Ok(())
-}
\ No newline at end of file
+}
@@ -3,8 +3,48 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "between 18 and 21 degrees", "18 to 21 degrees", "from 18 to 21 degrees");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "below 5 degrees", "under 5 degrees");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("celsius")), "above 30 degrees celsius", "over 30 celsius");
+    example!(v, check_temperature_interval(Some(20.0), None, true, false, Some("degree")), "at least 20 degrees");
+    example!(v, check_temperature_interval(None, Some(25.0), false, true, Some("degree")), "at most 25 degrees");
+    example!(v, check_temperature(-5.0, Some("degree")), "5 degrees below zero");
+    example!(v, check_temperature(-3.0, Some("degree")), "minus 3 degrees");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300 kelvin", "300°k");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "two degrees warmer", "2 degrees higher");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "three degrees colder", "3 degrees cooler");
+}
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5 km", "five kilometers", "5 kilometres");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "three miles", "3 mi");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200 metres", "200 m");
+    example!(v, check_distance(74.0, Some("inch"), Precision::Exact), "6 ft 2 in", "6 feet 2 inches", "six feet and two inches");
+    example!(v, check_distance(180.0, Some("centimetre"), Precision::Exact), "1 m 80 cm");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "about 10 km", "around ten kilometers");
+}
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "under $50", "less than 50 dollars", "below $50");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "over 100 euros", "more than €100");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("EUR")), "between 20 and 30 euros", "between 20 euros and 30 euros", "20-30 euros", "from 20 to 30 euros");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("$")), "$20-$30", "$20 to $30", "$20-30");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, Some("kilogram"), Precision::Exact, None), "2 kg", "two kilograms", "2 kilos");
+    example!(v, check_quantity(0.5, Some("pound"), Precision::Exact, None), "half a pound", "1/2 lb");
+    example!(v, check_quantity(3.0, Some("cup"), Precision::Exact, None), "3 cups", "three cups");
+    example!(v, check_quantity(500.0, Some("millilitre"), Precision::Exact, None), "500 ml", "500 millilitres");
+    example!(v, check_quantity(1.5, Some("cup"), Precision::Exact, None), "1 1/2 cups", "one and a half cups", "a cup and a half");
+    example!(v, check_quantity(1.0, Some("tablespoon"), Precision::Exact, Some("sugar")), "a tablespoon of sugar", "1 tbsp of sugar");
+    example!(v, check_quantity(0.75, Some("cup"), Precision::Exact, Some("flour")), "three quarters of a cup of flour", "3/4 cup of flour");
+    example!(v, check_quantity(1.0, Some("litre"), Precision::Approximate, None), "about a litre", "around 1 l");
+}
+
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "now", "right now", "just now");
     example!(v, check_moment!(c, [2013, 2, 12]), "today", "at this time");
     example!(v, check_moment!(c, [2013, 2, 11]), "yesterday");
@@ -64,6 +104,14 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 10, 7], Grain::Week), "the week of october 6th", "the week of october 7th");
     example!(v, check_moment!(c, [2015, 10, 31]), "last day of october 2015", "last day in october 2015");
     example!(v, check_moment!(c, [2014, 9, 22], Grain::Week), "last week of september 2014");
+    example!(v, check_moment!(c, [2013, 2, 13]), "next business day", "the next working day");
+    example!(v, check_moment!(c, [2013, 2, 11]), "previous business day");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 business days", "3 working days from now");
+    example!(v, check_moment!(c, [2013, 2, 19]), "in 5 working days");
+    example!(v, check_moment!(c, [2013, 2, 8]), "2 business days ago");
+    example!(v, check_moment!(c, [2013, 2, 28]), "the last business day of the month");
+    example!(v, check_moment!(c, [2013, 3, 29]), "last business day of march");
+    example!(v, check_moment!(c, [2013, 3, 1]), "the first working day of march");
     //nth of
     example!(v, check_moment!(c, [2013, 10, 1]), "first tuesday of october", "first tuesday in october");
     example!(v, check_moment!(c, [2014, 9, 16]), "third tuesday of september 2014");
@@ -181,6 +229,21 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2017, 05, 12, 10, 32], [2017, 06, 7, 18, 23]), "friday the 12th of may, 10:32 am to wednesday the 7th of june, 6:22 pm");
 }
 
+pub fn examples_recurrences(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence!(c, "FREQ=DAILY"), "every day", "each day", "daily", "everyday");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY"), "every week", "weekly", "once a week");
+    example!(v, check_recurrence!(c, "FREQ=DAILY;INTERVAL=2"), "every other day", "every 2 days");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;INTERVAL=3"), "every 3 weeks", "every three weeks");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=MO"), "every monday", "each monday");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO"), "every other monday");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=TU;BYHOUR=15"), "every tuesday at 3pm", "every tuesday 3pm");
+    example!(v, check_recurrence!(c, "FREQ=DAILY;BYHOUR=9"), "every day at 9am", "daily at 9am");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY", times 2), "twice a week", "2 times a week", "two times per week");
+    example!(v, check_recurrence!(c, "FREQ=DAILY", times 3), "three times a day", "thrice a day");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=MO", until [2013, 7, 1]), "every monday until june");
+}
+
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "during two hours", "for 2 hours");
     example!(v, check_duration!([0, 0, 0, 1], Precision::Approximate), "about one day", "approximately 1 day");
@@ -236,7 +299,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "-1.2M",
              "-1200K",
              "-.0012G");
-    example!(v, check_integer(5000), "5 thousand", "five thousand");
+    example!(v, check_integer(5000), "5 thousand", "five thousand", "5k");
     example!(v, check_integer(122), "one twenty two");
     example!(v, check_integer(200000), "two hundred thousand");
     example!(v, check_integer(21011), "twenty-one thousand eleven");
@@ -251,4 +314,10 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "the 3rd", "3rd", "third");
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "between 3 and 5", "from 3 to 5", "3 to 5");
+    example!(v, check_number_range(Some(10.0), Some(20.0), true, true), "10-20", "between ten and twenty");
+    example!(v, check_number_range(Some(5.0), None, true, false), "at least 5", "at least five");
+    example!(v, check_number_range(Some(5.0), None, false, false), "more than 5", "over 5");
+    example!(v, check_number_range(None, Some(10.0), false, true), "up to 10", "at most ten");
+    example!(v, check_number_range(None, Some(10.0), false, false), "less than 10", "fewer than ten");
 }
\ No newline at end of file
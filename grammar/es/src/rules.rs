@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, DateOrder, PartOfDay, Season, PeriodComp};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -75,6 +75,56 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..AmountOfMoneyValue::default()
                  })
              });
+    b.rule_2("menos de <amount-of-money>",
+             b.reg(r#"menos de|por debajo de|menor que|m[áa]s barato que"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("como máximo <amount-of-money>",
+             b.reg(r#"hasta|como m[áa]ximo|a lo sumo|no m[áa]s de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("más de <amount-of-money>",
+             b.reg(r#"m[áa]s de|por encima de|mayor que|m[áa]s caro que"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("al menos <amount-of-money>",
+             b.reg(r#"al menos|por lo menos|como m[íi]nimo|no menos de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("entre <amount-of-money> y <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"y"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> a <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|a"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_4("de <amount-of-money> a <amount-of-money>",
+             b.reg(r#"de|desde"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"-|a|hasta"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
+    b.rule_2("<unit> <number range>",
+             money_unit!(),
+             number_range_check!(),
+             |a, b| helpers::money_range(b.value(), a.value())
+    );
     Ok(())
 }
 
@@ -586,11 +636,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  time.value().form_time_of_day()?.is_12_clock())
     );
     b.rule_1_terminal("dd[/-.]mm[/-.]yyyy",
-                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](0?[1-9]|1[0-2])[-/.](\d{2,4})"#)?,
-                      |text_match| helpers::ymd(
-                          text_match.group(3).parse()?,
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
                           text_match.group(2).parse()?,
-                          text_match.group(1).parse()?
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayFirst
                       )
     );
     b.rule_1_terminal("yyyy-mm-dd",
@@ -602,21 +653,23 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       )
     );
     b.rule_1_terminal("dd[/-]mm",
-                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](0?[1-9]|1[0-2])"#)?,
-                      |text_match| helpers::month_day(
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
                           text_match.group(2).parse()?,
-                          text_match.group(1).parse()?
+                          None,
+                          DateOrder::DayFirst
                       )
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"ma(?:ñ|n)ana"#)?,
-                      |_| Ok(helpers::hour(4, false)?.span_to(&helpers::hour(12, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                           .form(Form::PartOfDay(PartOfDayForm::Morning))
                           .latent())
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"tarde"#)?,
-                      |_| Ok(helpers::hour(12, false)?.span_to(&helpers::hour(19, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 19)?
                           .form(Form::PartOfDay(PartOfDayForm::Afternoon))
                           .latent())
     );
@@ -628,7 +681,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"noche"#)?,
-                      |_| Ok(helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                           .form(Form::PartOfDay(PartOfDayForm::Evening))
                           .latent())
     );
@@ -685,23 +738,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"verano"#)?,
-                      |_| helpers::month_day(6, 21)?
-                          .span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"oto[ñn]o"#)?,
-                      |_| helpers::month_day(9, 23)?
-                          .span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"invierno"#)?,
-                      |_| helpers::month_day(12, 21)?
-                          .span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"primavera"#)?,
-                      |_| helpers::month_day(3, 20)?
-                          .span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("el <time>",
              b.reg(r#"d?el"#)?,
@@ -768,6 +817,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            delta: false,
         })
     });
     b.rule_2("<latent temp> temp",
@@ -778,6 +828,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -788,6 +839,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -798,6 +850,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<latent temp> temp bajo cero",
@@ -810,6 +863,279 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"kelvin(?:e?s)?|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_2("menos <temp>",
+             b.reg(r#"menos"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0 && !temp.delta),
+             |_, a| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_4("entre <temp> y <temp>",
+             b.reg(r#"entre"#)?,
+             temperature_check!(),
+             b.reg(r#"y"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp> a <temp>",
+             temperature_check!(),
+             b.reg(r#"-|a"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_4("de <temp> a <temp>",
+             b.reg(r#"de|desde"#)?,
+             temperature_check!(),
+             b.reg(r#"-|a|hasta"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("más de <temp>",
+             b.reg(r#"m[áa]s de|por encima de|superior a"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("al menos <temp>",
+             b.reg(r#"al menos"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("menos de <temp>",
+             b.reg(r#"menos de|por debajo de|inferior a"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("como máximo <temp>",
+             b.reg(r#"como m[áa]ximo"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp> más",
+             temperature_check!(),
+             b.reg(r#"m[áa]s(?: caliente)?"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp> menos",
+             temperature_check!(),
+             b.reg(r#"menos|m[áa]s fr[íi]o"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Precision::Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"kms?|kil[oó]m(?:e|é)tros?"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"m|m(?:e|é)tros?"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"cm|cent[ií]m(?:e|é)tros?"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"mm|mil[ií]m(?:e|é)tros?"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> millas",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"millas?"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> pies",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"pies?"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> pulgadas",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"pulgadas?"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_3("<distance> and <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"y"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, _, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"(?:alrededor de|aproximadamente|cerca de|casi|unos|unas)"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"exactamente|justo"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("kilogram",
+                      b.reg(r#"kg|kilo(?:gramo)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "kilogram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("gram",
+                      b.reg(r#"g|gr|gramos?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("milligram",
+                      b.reg(r#"mg|miligramos?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "milligram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("pound",
+                      b.reg(r#"libras?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pound", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("ounce",
+                      b.reg(r#"onzas?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "ounce", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("litre",
+                      b.reg(r#"l|litros?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "litre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("centilitre",
+                      b.reg(r#"cl|centilitros?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "centilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("millilitre",
+                      b.reg(r#"ml|mililitros?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "millilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tazas?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "cup", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("tablespoon",
+                      b.reg(r#"cucharadas?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "tablespoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("teaspoon",
+                      b.reg(r#"cucharaditas?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "teaspoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("pint",
+                      b.reg(r#"pintas?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pint", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("gallon",
+                      b.reg(r#"gal[oó]n(?:es)?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gallon", family: QuantityFamily::Volume })
+    );
+    b.rule_2("<number> <unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, b| helpers::quantity(a.value().value(), b.value())
+    );
+    b.rule_2("<fraction> <unit>",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| helpers::quantity(helpers::fraction(text_match.group(1), text_match.group(2))?, a.value())
+    );
+    b.rule_2("<integer> <fraction> <unit>",
+             b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let integer: f32 = text_match.group(1).parse()?;
+                 helpers::quantity(integer + helpers::fraction(text_match.group(2), text_match.group(3))?, a.value())
+             });
+    b.rule_2("<fraction> of a <unit>",
+             b.reg(r#"(?:una? )?(medio|media|cuarto|tercio|tres cuartos|dos tercios)(?: de)?(?: una?)?"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let value = match text_match.group(1).as_ref() {
+                     "medio" => 0.5,
+                     "media" => 0.5,
+                     "cuarto" => 0.25,
+                     "tercio" => 1.0 / 3.0,
+                     "tres cuartos" => 0.75,
+                     "dos tercios" => 2.0 / 3.0,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::quantity(value, a.value())
+             });
+    b.rule_2("<quantity> and a half",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"y medi[oa]"#)?,
+             |a, _| {
+                 Ok(QuantityValue {
+                     value: a.value().value + 0.5,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:alrededor de|aproximadamente|cerca de|casi|unos|unas)"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"exactamente|justo"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"de (\w+)"#)?,
+             |a, text_match| {
+                 Ok(QuantityValue {
+                     product: Some(text_match.group(1).to_string()),
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -1079,5 +1405,45 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           };
                           Ok(OrdinalValue::new(value))
                       });
+    b.rule_4("entre <number> y <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"y"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number> a <number>",
+             number_check!(),
+             b.reg(r#"-|a"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_4("de <number> a <number>",
+             b.reg(r#"de|desde"#)?,
+             number_check!(),
+             b.reg(r#"-|a|hasta"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("al menos <number>",
+             b.reg(r#"al menos|por lo menos|como m[íi]nimo|no menos de"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("más de <number>",
+             b.reg(r#"m[áa]s de|por encima de|mayor que"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("hasta <number>",
+             b.reg(r#"hasta|como m[áa]ximo|a lo sumo|no m[áa]s de"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("menos de <number>",
+             b.reg(r#"menos de|por debajo de|menor que"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), false)
+    );
     Ok(())
 }
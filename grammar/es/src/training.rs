@@ -3,16 +3,49 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "entre 18 y 21 grados", "de 18 a 21 grados");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "menos de 5 grados");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("degree")), "más de 30 grados");
+    example!(v, check_temperature_interval(Some(20.0), None, true, false, Some("degree")), "al menos 20 grados");
+    example!(v, check_temperature_interval(None, Some(25.0), false, true, Some("degree")), "como máximo 25 grados");
+    example!(v, check_temperature(-3.0, Some("degree")), "menos 3 grados", "menos tres grados");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300 kelvin", "300°k");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "dos grados más");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "tres grados menos");
+}
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5 km", "cinco kilómetros");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "tres millas");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200 metros", "200 m");
+    example!(v, check_distance(180.0, Some("centimetre"), Precision::Exact), "1 m 80 cm", "1 metro y 80 centímetros");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "unos 10 km", "aproximadamente diez kilómetros");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, Some("kilogram"), Precision::Exact, None), "2 kg", "dos kilos");
+    example!(v, check_quantity(0.5, Some("kilogram"), Precision::Exact, None), "medio kilo", "1/2 kg");
+    example!(v, check_quantity(0.25, Some("litre"), Precision::Exact, None), "un cuarto de litro");
+    example!(v, check_quantity(2.5, Some("kilogram"), Precision::Exact, None), "dos kilos y medio");
+    example!(v, check_quantity(3.0, Some("cup"), Precision::Exact, Some("harina")), "tres tazas de harina", "3 tazas de harina");
+    example!(v, check_quantity(1.0, Some("tablespoon"), Precision::Exact, Some("azúcar")), "una cucharada de azúcar");
+    example!(v, check_quantity(500.0, Some("gram"), Precision::Approximate, None), "unos 500 gramos", "aproximadamente 500 g");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10$", "diez dólares");
     example!(v, check_finance(10000.0, Some("GBP"), Precision::Exact), "10.000 libras esterlina");
     example!(v, check_finance(20.0, Some("EUR"), Precision::Exact), "20€", "20 euros");
     example!(v, check_finance(9.0, Some("£"), Precision::Exact),  "£9", "nueve pounds", "9 libras");
     example!(v, check_finance(3.01, Some("GBP"), Precision::Exact), "3 gbp 1 centavo", "3 gbp y 1 centavo");
+    example!(v, check_finance_interval(None, Some(50.0), Some("EUR")), "menos de 50 euros", "menos de 50€", "como máximo cincuenta euros");
+    example!(v, check_finance_interval(Some(100.0), None, Some("$")), "más de 100 dólares", "al menos cien dólares");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("EUR")), "entre 20 y 30 euros", "entre 20 euros y 30 euros", "20-30 euros", "de 20 a 30 euros");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "ahora", "ya", "ahorita", "cuanto antes");
     example!(v, check_moment!(c, [2013, 2, 12]), "hoy", "en este momento");
     example!(v, check_moment!(c, [2013, 2, 11]), "ayer");
@@ -182,4 +215,10 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "1 punto cinco",
              "una punto cinco",
              "1,5");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "entre 3 y 5", "de 3 a 5", "3 a 5");
+    example!(v, check_number_range(Some(10.0), Some(20.0), true, true), "10-20", "entre diez y veinte");
+    example!(v, check_number_range(Some(5.0), None, true, false), "al menos 5", "por lo menos cinco");
+    example!(v, check_number_range(Some(5.0), None, false, false), "más de 5");
+    example!(v, check_number_range(None, Some(10.0), false, true), "hasta 10", "como máximo diez");
+    example!(v, check_number_range(None, Some(10.0), false, false), "menos de 10");
 }
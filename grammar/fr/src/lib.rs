@@ -17,20 +17,27 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
+    rules::rules_distance(&mut b)?;
+    rules::rules_quantity(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, NumberRange, Ordinal, Time, Duration, Temperature, TemperatureInterval, Distance, Quantity, AmountOfMoney, AmountOfMoneyInterval, Percentage, Recurrence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
     training::examples_time(&mut v);
+    training::examples_temperature(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_quantity(&mut v);
     training::examples_finance(&mut v);
+    training::examples_recurrences(&mut v);
     v
 }
 
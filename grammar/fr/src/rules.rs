@@ -2,7 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, DateOrder, PartOfDay, Season, PeriodComp, Period};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -121,6 +121,51 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             })
         }
     );
+    b.rule_2("moins de <amount-of-money>",
+             b.reg(r#"moins de|en dessous de|inf[ée]rieure? [àa]|moins cher que"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("au plus <amount-of-money>",
+             b.reg(r#"jusqu'(?:à|a)|au plus|au maximum|maximum|pas plus de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("plus de <amount-of-money>",
+             b.reg(r#"plus de|au[- ]dessus de|sup[ée]rieure? [àa]|plus cher que"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("au moins <amount-of-money>",
+             b.reg(r#"au moins|au minimum|minimum|pas moins de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("entre <amount-of-money> et <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"et"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> à <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|à"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_4("de <amount-of-money> à <amount-of-money>",
+             b.reg(r#"de"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"-|à"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
     Ok(())
 }
 
@@ -860,11 +905,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              )
     );
     b.rule_1_terminal("dd/-.mm/-.yyyy",
-        b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](1[0-2]|0?[1-9])[-/.](\d{2,4})"#)?,
-        |text_match| helpers::ymd(
-            text_match.group(3).parse()?,
+        b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+        |text_match| helpers::numeric_date(
+            text_match.group(1).parse()?,
             text_match.group(2).parse()?,
-            text_match.group(1).parse()?
+            Some(text_match.group(3).parse()?),
+            DateOrder::DayFirst
         )
     );
     b.rule_1_terminal("yyyy-mm-dd",
@@ -876,31 +922,35 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         )
     );
     b.rule_1_terminal("dd/-mm",
-        b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](1[0-2]|0?[1-9])"#)?,
-        |text_match| helpers::month_day(
-            text_match.group(2).parse()?,
+        b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](3[01]|[12]\d|0?[1-9])"#)?,
+        |text_match| helpers::numeric_date(
             text_match.group(1).parse()?,
+            text_match.group(2).parse()?,
+            None,
+            DateOrder::DayFirst
         )
     );
     b.rule_1_terminal("dd mm yyyy",
         b.reg(r#"(3[01]|[12]\d|0?[1-9]) (1[0-2]|0?[1-9]) (\d{2,4})"#)?,
-        |text_match| helpers::ymd(
-            text_match.group(3).parse()?,
+        |text_match| helpers::numeric_date(
+            text_match.group(1).parse()?,
             text_match.group(2).parse()?,
-            text_match.group(1).parse()?
+            Some(text_match.group(3).parse()?),
+            DateOrder::DayFirst
         )
     );
     b.rule_1_terminal("dd mm",
         b.reg(r#"(3[01]|[12]\d|0?[1-9]) (1[0-2]|0?[1-9])"#)?,
-        |text_match| helpers::month_day(
-            text_match.group(2).parse()?,
+        |text_match| helpers::numeric_date(
             text_match.group(1).parse()?,
+            text_match.group(2).parse()?,
+            None,
+            DateOrder::DayFirst
         )
     );
     b.rule_1_terminal("matin",
         b.reg(r#"mat(?:in[ée]?e?)?"#)?,
-        |_| Ok(helpers::hour(4, false)?
-                .span_to(&helpers::hour(12, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                 .latent()
                 .form(Form::PartOfDay(PartOfDayForm::Morning)))
     );
@@ -913,8 +963,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("petit dejeuner",
         b.reg(r#"petit[- ]d[ée]jeuner"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Breakfast, 5, 10)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -927,8 +976,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"brunch"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Brunch, 10, 15)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -941,8 +989,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("déjeuner",
         b.reg(r#"d[eéè]jeuner"#)?,
-        |_| Ok(helpers::hour(12, false)?
-                .span_to(&helpers::hour(14, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -989,8 +1036,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("après-midi",
         b.reg(r#"apr[eéè]s?[ \-]?midi|aprem"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                    .span_to(&helpers::hour(19, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 19)?
                     .latent()
                     .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
         }
@@ -1070,8 +1116,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("soir",
         b.reg(r#"soir[ée]?e?"#)?,
         |_| {
-            Ok(helpers::hour(18, false)?
-                    .span_to(&helpers::hour(0, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                     .latent()
                     .form(Form::PartOfDay(PartOfDayForm::Evening)))
         }
@@ -1096,15 +1141,13 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("diner",
         b.reg(r#"d[iî]ner|souper"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Dinner, 18, 23)?
                 .form(Form::Meal))
     );
     b.rule_1_terminal("nuit", 
         b.reg(r#"nuit"#)?,
         |_| {
-            Ok(helpers::hour(22, false)?
-                    .span_to(&helpers::hour(6, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Night, 22, 6)?
                     .latent()
                     .form(Form::PartOfDay(PartOfDayForm::Night)))
         }
@@ -1190,19 +1233,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?(?:été|ete)"#)?,
-        |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+        |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?automne"#)?,
-        |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+        |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?hiver"#)?,
-        |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+        |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:ce )?printemps"#)?,
-        |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+        |_| helpers::season(Season::Spring)
     );
     b.rule_2("le <time>",
              b.reg(r#"l[ea]"#)?,
@@ -1446,6 +1489,40 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(1, 31),
              |_, integer| Ok(helpers::day_of_month(integer.value().value as u32)?.mark_after_start())
     );
+    b.rule_1("jour ouvré suivant",
+             b.reg(r#"(?:le )?(?:prochain jour (?:ouvr[ée]|ouvrable)|jour (?:ouvr[ée]|ouvrable) suivant)"#)?,
+             |_| helpers::business_day_nth(1)
+    );
+    b.rule_1("jour ouvré précédent",
+             b.reg(r#"(?:le )?jour (?:ouvr[ée]|ouvrable) pr[ée]c[ée]dent"#)?,
+             |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("dans <integer> jours ouvrés",
+             b.reg(r#"dans|d'ici"#)?,
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"jours? (?:ouvr[ée]s?|ouvrables?)"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("il y a <integer> jours ouvrés",
+             b.reg(r#"il y a"#)?,
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"jours? (?:ouvr[ée]s?|ouvrables?)"#)?,
+             |_, integer, _| helpers::business_day_nth(-integer.value().value)
+    );
+    b.rule_2("premier jour ouvré de <time>",
+             b.reg(r#"(?:le )?premier jour (?:ouvr[ée]|ouvrable) (?:d['eu]|en)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_nth_after(0, time.value())
+    );
+    b.rule_2("dernier jour ouvré de <time>",
+             b.reg(r#"(?:le )?dernier jour (?:ouvr[ée]|ouvrable) (?:d['eu]|en)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day_last_of(time.value())
+    );
+    b.rule_1("dernier jour ouvré du mois",
+             b.reg(r#"(?:le )?dernier jour (?:ouvr[ée]|ouvrable) (?:du|de ce) mois"#)?,
+             |_| helpers::business_day_last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
     Ok(())
 }
 
@@ -1455,6 +1532,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            delta: false,
         })
     });
     b.rule_2("<latent temp> degrees",
@@ -1465,6 +1543,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -1475,6 +1554,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -1485,6 +1565,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<latent temp> en dessous de zero",
@@ -1497,6 +1578,269 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"kelvins?|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_4("entre <temp> et <temp>",
+             b.reg(r#"entre"#)?,
+             temperature_check!(),
+             b.reg(r#"et"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp> à <temp>",
+             temperature_check!(),
+             b.reg(r#"-|à"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_4("de <temp> à <temp>",
+             b.reg(r#"de"#)?,
+             temperature_check!(),
+             b.reg(r#"-|à"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("au-dessus de <temp>",
+             b.reg(r#"au[- ]dessus de|plus de|sup[ée]rieure? [àa]"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("au moins <temp>",
+             b.reg(r#"au moins"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("en dessous de <temp>",
+             b.reg(r#"en[- ]dessous de|au[- ]dessous de|moins de|inf[ée]rieure? [àa]"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("au plus <temp>",
+             b.reg(r#"au plus"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp> de plus",
+             temperature_check!(),
+             b.reg(r#"de plus|plus chaud"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp> de moins",
+             temperature_check!(),
+             b.reg(r#"de moins|plus froid"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"k(?:ilo)?m(?:[eè]tres?)?|kms"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"m|m[eè]tres?"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"cm|centim[eè]tres?"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"mm|millim[eè]tres?"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> miles",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"miles?"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> pieds",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"pieds?"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> pouces",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"pouces?"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_3("<distance> and <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"et"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, _, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"(?:environ|approximativement|à peu près|près de|autour de|presque)"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"exactement|précisément"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("kilogram",
+                      b.reg(r#"kg|kilo(?:gramme)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "kilogram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("gram",
+                      b.reg(r#"g|gr|grammes?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("milligram",
+                      b.reg(r#"mg|milligrammes?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "milligram", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("pound",
+                      b.reg(r#"livres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pound", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("ounce",
+                      b.reg(r#"onces?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "ounce", family: QuantityFamily::Mass })
+    );
+    b.rule_1_terminal("litre",
+                      b.reg(r#"l|litres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "litre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("centilitre",
+                      b.reg(r#"cl|centilitres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "centilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("millilitre",
+                      b.reg(r#"ml|millilitres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "millilitre", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tasses?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "cup", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("tablespoon",
+                      b.reg(r#"cuill[eè]res? (?:à|a) soupe|c\. ?(?:à|a) ?s\.?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "tablespoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("teaspoon",
+                      b.reg(r#"cuill[eè]res? (?:à|a) caf[eé]|c\. ?(?:à|a) ?c\.?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "teaspoon", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("pint",
+                      b.reg(r#"pintes?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "pint", family: QuantityFamily::Volume })
+    );
+    b.rule_1_terminal("gallon",
+                      b.reg(r#"gallons?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: "gallon", family: QuantityFamily::Volume })
+    );
+    b.rule_2("<number> <unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, b| helpers::quantity(a.value().value(), b.value())
+    );
+    b.rule_2("<fraction> <unit>",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| helpers::quantity(helpers::fraction(text_match.group(1), text_match.group(2))?, a.value())
+    );
+    b.rule_2("<integer> <fraction> <unit>",
+             b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let integer: f32 = text_match.group(1).parse()?;
+                 helpers::quantity(integer + helpers::fraction(text_match.group(2), text_match.group(3))?, a.value())
+             });
+    b.rule_2("<fraction> of a <unit>",
+             b.reg(r#"(?:une? )?(demie?|quart|tiers|trois quarts|deux tiers)(?: d[e'](?: une?)?|-)?"#)?,
+             quantity_unit!(),
+             |text_match, a| {
+                 let value = match text_match.group(1).as_ref() {
+                     "demi" => 0.5,
+                     "demie" => 0.5,
+                     "quart" => 0.25,
+                     "tiers" => 1.0 / 3.0,
+                     "trois quarts" => 0.75,
+                     "deux tiers" => 2.0 / 3.0,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::quantity(value, a.value())
+             });
+    b.rule_2("<quantity> and a half",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"et demie?"#)?,
+             |a, _| {
+                 Ok(QuantityValue {
+                     value: a.value().value + 0.5,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:environ|approximativement|à peu près|près de|autour de|presque)"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"exactement|précisément"#)?,
+             quantity_check!(),
+             |_, a| {
+                 Ok(QuantityValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"d(?:e |')(\w+)"#)?,
+             |a, text_match| {
+                 Ok(QuantityValue {
+                     product: Some(text_match.group(1).to_string()),
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -1977,5 +2321,115 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              ordinal_check!(),
              |_, a| Ok((*a.value()).prefixed())
     );
+    b.rule_4("entre <number> et <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"et"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number> à <number>",
+             number_check!(),
+             b.reg(r#"-|à"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_4("de <number> à <number>",
+             b.reg(r#"de"#)?,
+             number_check!(),
+             b.reg(r#"-|à"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("au moins <number>",
+             b.reg(r#"au moins|au minimum|minimum|pas moins de"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("plus de <number>",
+             b.reg(r#"plus de|au[- ]dessus de|sup[ée]rieure? [àa]"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("jusqu'à <number>",
+             b.reg(r#"jusqu'(?:à|a)|au plus|au maximum|maximum|pas plus de"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("moins de <number>",
+             b.reg(r#"moins de|en dessous de|inf[ée]rieure? [àa]"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), false)
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("tous les|chaque <cycle>",
+             b.reg(r#"tou(?:te)?s les|chaque"#)?,
+             cycle_check!(),
+             |_, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain))
+    );
+    b.rule_1_terminal("quotidien",
+                      b.reg(r#"quotidien(?:ne)?(?:ment)?"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Day))
+    );
+    b.rule_1_terminal("hebdomadaire",
+                      b.reg(r#"hebdomadaire(?:ment)?"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Week))
+    );
+    b.rule_1_terminal("mensuel",
+                      b.reg(r#"mensuel(?:le)?(?:ment)?"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Month))
+    );
+    b.rule_1_terminal("annuel",
+                      b.reg(r#"annuel(?:le)?(?:ment)?"#)?,
+                      |_| Ok(RecurrenceValue::every_cycle(Grain::Year))
+    );
+    b.rule_3("tous les <integer> <cycle>",
+             b.reg(r#"tou(?:te)?s les"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, integer, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain).interval(integer.value().value))
+    );
+    b.rule_3("un <cycle> sur deux",
+             b.reg(r#"une?"#)?,
+             cycle_check!(),
+             b.reg(r#"sur deux"#)?,
+             |_, cycle, _| Ok(RecurrenceValue::every_cycle(cycle.value().grain).interval(2))
+    );
+    b.rule_2("chaque <time>",
+             b.reg(r#"chaque"#)?,
+             time_check!(|time: &TimeValue| time.direction.is_none()),
+             |_, time| Ok(RecurrenceValue::every_time(time.value().clone()))
+    );
+    b.rule_3("un <time> sur deux",
+             b.reg(r#"une?"#)?,
+             time_check!(|time: &TimeValue| time.direction.is_none()),
+             b.reg(r#"sur deux"#)?,
+             |_, time, _| Ok(RecurrenceValue::every_time(time.value().clone()).interval(2))
+    );
+    b.rule_2("une fois par <cycle>",
+             b.reg(r#"une fois par"#)?,
+             cycle_check!(),
+             |_, cycle| Ok(RecurrenceValue::every_cycle(cycle.value().grain))
+    );
+    b.rule_3("<integer> fois par <cycle>",
+             integer_check_by_range!(2),
+             b.reg(r#"fois par"#)?,
+             cycle_check!(),
+             |integer, _, cycle| Ok(RecurrenceValue::times_per_cycle(integer.value().value, cycle.value().grain))
+    );
+    b.rule_2("<recurrence> <time>",
+             recurrence_check!(),
+             time_check!(),
+             |recurrence, time| recurrence.value().at(time.value())
+    );
+    b.rule_3("<recurrence> jusqu'à <time>",
+             recurrence_check!(),
+             b.reg(r#"jusqu'? ?(?:au|à|a|en)"#)?,
+             time_check!(),
+             |recurrence, _, time| recurrence.value().until(time.value())
+    );
     Ok(())
 }
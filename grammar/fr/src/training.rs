@@ -3,15 +3,49 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "entre 18 et 21 degrés", "de 18 à 21 degrés");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "en dessous de 5 degrés");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("degree")), "au-dessus de 30 degrés");
+    example!(v, check_temperature_interval(Some(20.0), None, true, false, Some("degree")), "au moins 20 degrés");
+    example!(v, check_temperature_interval(None, Some(25.0), false, true, Some("degree")), "au plus 25 degrés");
+    example!(v, check_temperature(-3.0, Some("degree")), "moins 3 degrés");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300 kelvins", "300°k");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "deux degrés de plus");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "trois degrés de moins");
+}
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5 km", "cinq kilomètres");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "trois miles");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200 mètres", "200 m");
+    example!(v, check_distance(180.0, Some("centimetre"), Precision::Exact), "1 m 80 cm", "1 mètre et 80 centimètres");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "environ 10 km", "à peu près dix kilomètres");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, Some("kilogram"), Precision::Exact, None), "2 kg", "deux kilos");
+    example!(v, check_quantity(0.5, Some("litre"), Precision::Exact, None), "un demi litre", "1/2 litre");
+    example!(v, check_quantity(0.25, Some("litre"), Precision::Exact, None), "un quart de litre");
+    example!(v, check_quantity(2.5, Some("kilogram"), Precision::Exact, None), "deux kilos et demi", "2 kg et demi");
+    example!(v, check_quantity(1.0, Some("tablespoon"), Precision::Exact, None), "une cuillère à soupe");
+    example!(v, check_quantity(3.0, Some("cup"), Precision::Exact, Some("farine")), "trois tasses de farine", "3 tasses de farine");
+    example!(v, check_quantity(250.0, Some("gram"), Precision::Exact, Some("beurre")), "250 g de beurre", "250 grammes de beurre");
+    example!(v, check_quantity(1.0, Some("litre"), Precision::Approximate, None), "environ un litre", "à peu près 1 l");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2000.0, Some("$"), Precision::Exact), "deux milles dollars");
     example!(v, check_finance(10.0, Some("GBP"), Precision::Exact), "dix livres sterlings");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "deux euros et cinq centimes");
     example!(v, check_finance(2.0, Some("CHF"), Precision::Exact), "deux francs suisse");
+    example!(v, check_finance_interval(None, Some(50.0), Some("EUR")), "moins de 50 euros", "moins de 50 €", "pas plus de cinquante euros");
+    example!(v, check_finance_interval(Some(100.0), None, Some("$")), "plus de 100 dollars", "au moins cent dollars");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("EUR")), "entre 20 et 30 euros", "entre 20 euros et 30 euros", "20-30 euros", "de 20 à 30 euros");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "maintenant", "tout de suite");
     example!(v, check_moment!(c, [2013, 2, 12]), "aujourd'hui", "ce jour", "dans la journée", "en ce moment");
     example!(v, check_moment!(c, [2013, 2, 11]), "hier", "le jour d'avant", "le jour précédent", "la veille");
@@ -78,6 +112,13 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 10, 7], Grain::Week), "la semaine du 6 octobre", "la semaine du 7 octobre");
     example!(v, check_moment!(c, [2015, 10, 31]), "dernier jour d'octobre 2015", "le dernier jour d'octobre 2015");
     example!(v, check_moment!(c, [2014, 9, 22], Grain::Week), "dernière semaine de septembre 2014", "la dernière semaine de septembre 2014");
+    example!(v, check_moment!(c, [2013, 2, 13]), "le prochain jour ouvré", "jour ouvrable suivant");
+    example!(v, check_moment!(c, [2013, 2, 11]), "le jour ouvré précédent");
+    example!(v, check_moment!(c, [2013, 2, 15]), "dans 3 jours ouvrés", "d'ici trois jours ouvrables");
+    example!(v, check_moment!(c, [2013, 2, 8]), "il y a 2 jours ouvrés");
+    example!(v, check_moment!(c, [2013, 2, 28]), "le dernier jour ouvré du mois");
+    example!(v, check_moment!(c, [2013, 3, 29]), "dernier jour ouvré de mars");
+    example!(v, check_moment!(c, [2013, 3, 1]), "le premier jour ouvrable de mars");
     //Hours
     example!(v, check_moment!(c, [2013, 2, 12, 15]), "à quinze heures", "à 15 heures", "à 3 heures cet après-midi", "15h", "15H", "vers 15 heures", "à environ 15 heures");
     example!(v, check_moment!(c, [2013, 2, 12, 15, 0]), "15:00", "15h00", "15H00");
@@ -208,6 +249,21 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16");
 }
 
+pub fn examples_recurrences(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence!(c, "FREQ=DAILY"), "tous les jours", "chaque jour", "quotidiennement");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY"), "toutes les semaines", "chaque semaine", "une fois par semaine", "hebdomadaire");
+    example!(v, check_recurrence!(c, "FREQ=DAILY;INTERVAL=2"), "tous les deux jours", "un jour sur deux");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;INTERVAL=3"), "toutes les trois semaines", "toutes les 3 semaines");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=MO"), "chaque lundi");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO"), "un lundi sur deux");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=TU;BYHOUR=15"), "chaque mardi à 15h", "chaque mardi à quinze heures");
+    example!(v, check_recurrence!(c, "FREQ=DAILY;BYHOUR=12"), "tous les jours à midi");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY", times 2), "deux fois par semaine", "2 fois par semaine");
+    example!(v, check_recurrence!(c, "FREQ=DAILY", times 3), "trois fois par jour");
+    example!(v, check_recurrence!(c, "FREQ=WEEKLY;BYDAY=MO", until [2013, 7, 1]), "chaque lundi jusqu'en juin");
+}
+
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "pendant deux heures", "durant deux heures");
     example!(v, check_duration!([0, 0, 0, 1]), "pendant un jour", "une journée");
@@ -289,4 +345,10 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "3ème",
              "troisième",
              "troisieme");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "entre 3 et 5", "de 3 à 5", "3 à 5");
+    example!(v, check_number_range(Some(10.0), Some(20.0), true, true), "10-20", "entre dix et vingt");
+    example!(v, check_number_range(Some(5.0), None, true, false), "au moins 5", "au moins cinq");
+    example!(v, check_number_range(Some(5.0), None, false, false), "plus de 5");
+    example!(v, check_number_range(None, Some(10.0), false, true), "jusqu'à 10", "au plus dix");
+    example!(v, check_number_range(None, Some(10.0), false, false), "moins de 10");
 }
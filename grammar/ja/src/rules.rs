@@ -2,7 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PartOfDay, Season, PeriodComp, Period};
 
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
@@ -149,6 +149,44 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             // ..FloatValue::default()
         // })
     // });
+    b.rule_4("<number>と<number>の間",
+             number_check!(),
+             b.reg(r#"と"#)?,
+             number_check!(),
+             b.reg(r#"の間"#)?,
+             |a, _, b, _| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number>から<number>",
+             number_check!(),
+             b.reg(r#"から|〜|~|-"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("<number range>まで",
+             number_range_check!(|range: &NumberRangeValue| range.min.is_some() && range.max.is_some()),
+             b.reg(r#"まで"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<number>以上",
+             number_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("<number>を超える",
+             number_check!(),
+             b.reg(r#"を?超える?|より(?:多い|上)"#)?,
+             |a, _| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("<number>以下",
+             number_check!(),
+             b.reg(r#"以下|まで"#)?,
+             |a, _| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("<number>未満",
+             number_check!(),
+             b.reg(r#"未満|より(?:少ない|下)"#)?,
+             |a, _| helpers::number_range_to(a.value(), false)
+    );
     Ok(())
 }
 
@@ -284,6 +322,49 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_2("<amount-of-money>未満",
+             amount_of_money_check!(),
+             b.reg(r#"未満|より(?:安い|少ない|下)"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("<amount-of-money>以下",
+             amount_of_money_check!(),
+             b.reg(r#"以下|まで"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<amount-of-money>超",
+             amount_of_money_check!(),
+             b.reg(r#"を?超える?|より(?:高い|多い|上)"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("<amount-of-money>以上",
+             amount_of_money_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("<amount-of-money>と<amount-of-money>の間",
+             amount_of_money_check!(),
+             b.reg(r#"と"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"の間"#)?,
+             |a, _, b, _| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money>から<amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"から|〜|~|-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<amount-of-money interval>まで",
+             amount_of_money_interval_check!(|interval: &AmountOfMoneyIntervalValue| interval.direction().is_none()),
+             b.reg(r#"まで"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
     Ok(())
 }
 
@@ -295,6 +376,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value as f32,
                 unit: None,
                 latent: true,
+                delta: false,
             })
     });
     b.rule_2("<temp> Celcius",
@@ -305,6 +387,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              });
     b.rule_2("<latent temp> below zero",
@@ -317,6 +400,146 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"ケルビン|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_4("<temp>と<temp>の間",
+             temperature_check!(),
+             b.reg(r#"と"#)?,
+             temperature_check!(),
+             b.reg(r#"の間"#)?,
+             |a, _, b, _| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp>から<temp>",
+             temperature_check!(),
+             b.reg(r#"から|〜|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("<temp interval>まで",
+             temperature_interval_check!(|interval: &TemperatureIntervalValue| interval.direction().is_none()),
+             b.reg(r#"まで"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<temp>超",
+             temperature_check!(),
+             b.reg(r#"を?超える?|より(?:高い|上)"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("<temp>以上",
+             temperature_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("<temp>未満",
+             temperature_check!(),
+             b.reg(r#"未満|より(?:低い|下)"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("<temp>以下",
+             temperature_check!(),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp>上げ",
+             temperature_check!(),
+             b.reg(r#"上げ(?:て|る)?|高く|暖かく"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp>下げ",
+             temperature_check!(),
+             b.reg(r#"下げ(?:て|る)?|低く|涼しく"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"キロメートル|キロ|km"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"メートル|m"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"センチメートル|センチ|cm"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"ミリメートル|ミリ|mm"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> マイル",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"マイル"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> フィート",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"フィート"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> インチ",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"インチ"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"約|およそ|だいたい"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<distance> about",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"くらい|ぐらい|ほど"#)?,
+             |a, _| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"ちょうど"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -1107,16 +1330,14 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("morning",
         b.reg(r#"朝の?|午前中?|今朝"#)?,
         |_| {
-            Ok(helpers::hour(4, false)?
-                .span_to(&helpers::hour(12, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                 .latent()
                 .form(Form::PartOfDay(PartOfDayForm::Morning)))
         }
     );
     b.rule_1_terminal("breakfast",
         b.reg(r#"朝(?:食|ごはん|ご飯)"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Breakfast, 5, 10)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -1148,8 +1369,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("afternoon",
         b.reg(r#"午後"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                .span_to(&helpers::hour(17, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 17)?
                 .latent()
                 .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
         }
@@ -1157,8 +1377,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("evening",
         b.reg(r#"夕方"#)?,
         |_| {
-            Ok(helpers::hour(16, false)?
-                .span_to(&helpers::hour(19, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Evening, 16, 19)?
                 .latent()
                 .form(Form::PartOfDay(PartOfDayForm::Evening)))
         }
@@ -1167,24 +1386,21 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("night",
         b.reg(r#"夜|晩|晚"#)?,
         |_| {
-            Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(0, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Night, 18, 0)?
                 .latent()
                 .form(Form::PartOfDay(PartOfDayForm::Night)))
         }
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"ブランチ"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Brunch, 10, 15)?
                 .latent()
                 .form(Form::Meal))
     );
     b.rule_1_terminal("lunch",
         b.reg(r#"昼食|お昼ご飯|昼ごはん"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                .span_to(&helpers::hour(14, false)?, false)?
+            Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                 .latent()
                 .form(Form::Meal))
         }
@@ -1192,8 +1408,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("dinner",
         b.reg(r#"夕(?:食|ご飯|ごはん)|晩(?:御飯|ご(?:飯|はん))"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
+        |_| Ok(helpers::part_of_day(PartOfDay::Dinner, 18, 23)?
                 .latent()
                 .form(Form::Meal))
     );
@@ -1428,19 +1643,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"夏"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"秋"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"冬"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"春"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("<time-of-day> approximately",
              b.reg(r#"だいたい"#)?,
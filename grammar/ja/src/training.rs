@@ -3,6 +3,21 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "五キロ", "五キロメートル");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "二百メートル");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "三マイル");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "約十キロ", "十キロくらい");
+}
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance(10.0, Some("$"), Precision::Exact), "10ドル", "十ドル");
+    example!(v, check_finance(500.0, Some("JPY"), Precision::Approximate), "約500円", "500円くらい");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "50ドル未満", "50ドル以下", "50ドルまで", "50ドルより安い");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "100ユーロ以上", "100ユーロを超える", "100ユーロより高い");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("JPY")), "20円と30円の間", "20円から30円", "20円〜30円", "20円から30円まで", "20から30円");
+}
+
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(0), "零");
     example!(v, check_integer(1), "一");
@@ -26,6 +41,11 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(7), "七番目");
     example!(v, check_ordinal(11), "十一番目");
     example!(v, check_ordinal(91), "九十一番目");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "3から5", "3から5まで", "3〜5", "3と5の間");
+    example!(v, check_number_range(Some(5.0), None, true, false), "5以上");
+    example!(v, check_number_range(Some(5.0), None, false, false), "5を超える");
+    example!(v, check_number_range(None, Some(10.0), false, true), "10以下", "10まで");
+    example!(v, check_number_range(None, Some(10.0), false, false), "10未満");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -33,6 +53,14 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(25.0, Some("celsius")), "二十五度");
     example!(v, check_temperature(-10.0, Some("celsius")), "マイナス十度");
     example!(v, check_temperature(-10.0, Some("celsius")), "零下十度");
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("celsius")), "18度から21度", "18から21度", "18度と21度の間");
+    example!(v, check_temperature_interval(None, Some(5.0), false, true, Some("celsius")), "5度以下");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("celsius")), "5度未満");
+    example!(v, check_temperature_interval(Some(30.0), None, true, false, Some("celsius")), "30度以上");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("celsius")), "30度を超える");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300ケルビン");
+    example!(v, check_temperature_delta(2.0, Some("celsius")), "2度上げて");
+    example!(v, check_temperature_delta(-3.0, Some("celsius")), "3度下げて");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -44,7 +72,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 10]), "一昨日", "二千十三年二月十日", "前の日曜日", "先週の日曜日");
     example!(v, check_moment!(c, [2013, 2, 11]), "昨日", "前の日", "前日");
     example!(v, check_moment!(c, [2013, 2, 13]), "明日", "次の日", "二千十三年二月十三日", "今週の水曜日", "バレンタインデーの前の日"); 
@@ -18,12 +18,13 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
+    rules::rules_distance(&mut b)?;
     rules::rules_finance(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney];
+    return vec![Number, NumberRange, Ordinal, Time, Duration, Temperature, TemperatureInterval, Distance, AmountOfMoney, AmountOfMoneyInterval];
 }
 
 
@@ -31,6 +32,7 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     let mut v = vec![];
     training::examples_numbers(&mut v);
     training::examples_time(&mut v);
+    training::examples_distance(&mut v);
     training::examples_finance(&mut v);
     training::examples_temperature(&mut v);
     v
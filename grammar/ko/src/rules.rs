@@ -3,7 +3,7 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
 use regex::Regex;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PartOfDay, Season, PeriodComp};
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
@@ -101,6 +101,64 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_2("<amount-of-money> 미만",
+             amount_of_money_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("<amount-of-money> 이하",
+             amount_of_money_check!(),
+             b.reg(r#"이하|까지"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("최대 <amount-of-money>",
+             b.reg(r#"최대|많아야"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<amount-of-money> 초과",
+             amount_of_money_check!(),
+             b.reg(r#"초과|넘게|넘는"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("<amount-of-money> 이상",
+             amount_of_money_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("최소 <amount-of-money>",
+             b.reg(r#"최소|적어도"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After, true)
+    );
+    b.rule_4("<amount-of-money>과 <amount-of-money> 사이",
+             amount_of_money_check!(),
+             b.reg(r#"과|와|하고"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"사이"#)?,
+             |a, _, b, _| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money>에서 <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"에서|부터|~|-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_between(a.value(), b.value())
+    );
+    b.rule_2("<amount-of-money interval>까지",
+             amount_of_money_interval_check!(|interval: &AmountOfMoneyIntervalValue| interval.direction().is_none()),
+             b.reg(r#"까지"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<number range> <unit>",
+             number_range_check!(),
+             money_unit!(),
+             |a, b| helpers::money_range(a.value(), b.value())
+    );
+    b.rule_2("<unit> <number range>",
+             money_unit!(),
+             number_range_check!(),
+             |a, b| helpers::money_range(b.value(), a.value())
+    );
     Ok(())
 }
 
@@ -111,6 +169,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value(),
                  unit: None,
                  latent: true,
+                 delta: false,
              })
     );
     b.rule_2("below <temp>",
@@ -122,6 +181,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -137,6 +197,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         delta: false,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -151,6 +212,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value,
                  unit: Some("degree"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("섭씨 <temp> (celsius)",
@@ -160,6 +222,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value,
                  unit: Some("celsius"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> °C",
@@ -169,6 +232,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value,
                  unit: Some("celsius"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("화씨 <temp>",
@@ -178,6 +242,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 delta: false,
              })
     );
     b.rule_2("<temp> °F",
@@ -187,8 +252,149 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 delta: false,
              })
     );
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"켈빈|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_4("<temp>과 <temp> 사이",
+             temperature_check!(),
+             b.reg(r#"과|와|하고"#)?,
+             temperature_check!(),
+             b.reg(r#"사이"#)?,
+             |a, _, b, _| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp>에서 <temp>",
+             temperature_check!(),
+             b.reg(r#"에서|부터|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("<temp interval>까지",
+             temperature_interval_check!(|interval: &TemperatureIntervalValue| interval.direction().is_none()),
+             b.reg(r#"까지"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<temp> 초과",
+             temperature_check!(),
+             b.reg(r#"초과"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("<temp> 이상",
+             temperature_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("<temp> 미만",
+             temperature_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("<temp> 이하",
+             temperature_check!(),
+             b.reg(r#"이하"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("<temp> 높게",
+             temperature_check!(),
+             b.reg(r#"높게|올려|따뜻하게"#)?,
+             |a, _| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("<temp> 낮게",
+             temperature_check!(),
+             b.reg(r#"낮게|내려|시원하게"#)?,
+             |a, _| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"킬로미터|킬로|km"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"미터|m"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"센티미터|센티|cm"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"밀리미터|밀리|mm"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> 마일",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"마일"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> 피트",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"피트"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> 인치",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"인치"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"약|대략"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<distance> about",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"정도"#)?,
+             |a, _| {
+                 Ok(DistanceValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"정확히"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -769,14 +975,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"아침"#)?,
-                      |_| Ok(helpers::hour(4, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                           .form(Form::PartOfDay(PartOfDayForm::Morning)))
     );
     b.rule_1_terminal("morning (latent)",
                       b.reg(r#"오전"#)?,
-                      |_| Ok(helpers::hour(4, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                           .latent()
                           .form(Form::PartOfDay(PartOfDayForm::Morning)))
     );
@@ -796,8 +1000,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"오후"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(19, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 19)?
                           .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
     );
     b.rule_1_terminal("late afternoon (latent)",
@@ -816,8 +1019,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"저녁"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                           .form(Form::PartOfDay(PartOfDayForm::Evening)))
     );
     b.rule_1_terminal("late evening (latent)",
@@ -836,8 +1038,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("night",
                       b.reg(r#"밤"#)?,
-                      |_| Ok(helpers::hour(19, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Night, 19, 0)?
                           .form(Form::PartOfDay(PartOfDayForm::Night)))
     );
     b.rule_1_terminal("late night (latent)",
@@ -849,22 +1050,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("breakfast (latent)",
                       b.reg(r#"아침(?: ?(?:식사|밥))?|조반"#)?,
-                      |_| Ok(helpers::hour(6, false)?
-                          .span_to(&helpers::hour(9, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Breakfast, 6, 9)?
                           .latent()
                           .form(Form::Meal))
     );
     b.rule_1_terminal("brunch (latent)",
                       b.reg(r#"브런취|브런치|아침 겸 점심|늦은 아침|아점"#)?,
-                      |_| Ok(helpers::hour(11, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Brunch, 11, 14)?
                           .latent()
                           .form(Form::Meal))
     );
     b.rule_1_terminal("lunch (latent)",
                       b.reg(r#"점심(?: ?(?:식사|밥))?"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
+                      |_| Ok(helpers::part_of_day(PartOfDay::Lunch, 12, 14)?
                           .latent()
                           .form(Form::Meal))
     );
@@ -899,19 +1097,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"여름"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"가을"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"겨울"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"봄"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("<time> approximately",
              time_check!(),
@@ -1531,5 +1729,53 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| FloatValue::new(a.value().value() / b.value().value())
     );
+    b.rule_4("<number>과 <number> 사이",
+             number_check!(),
+             b.reg(r#"과|와|하고"#)?,
+             number_check!(),
+             b.reg(r#"사이"#)?,
+             |a, _, b, _| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number>에서 <number>",
+             number_check!(),
+             b.reg(r#"에서|부터|~|-"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("<number range>까지",
+             number_range_check!(|range: &NumberRangeValue| range.min.is_some() && range.max.is_some()),
+             b.reg(r#"까지"#)?,
+             |a, _| Ok(*a.value())
+    );
+    b.rule_2("<number> 이상",
+             number_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("<number> 초과",
+             number_check!(),
+             b.reg(r#"초과"#)?,
+             |a, _| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("최대 <number>",
+             b.reg(r#"최대|많아야"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("<number> 이하",
+             number_check!(),
+             b.reg(r#"이하|까지"#)?,
+             |a, _| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("<number> 미만",
+             number_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::number_range_to(a.value(), false)
+    );
+    b.rule_2("최소 <number>",
+             b.reg(r#"최소|적어도"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
     Ok(())
 }
@@ -3,6 +3,13 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5킬로미터", "오킬로미터");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200미터");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "3마일");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "약 10킬로미터", "10킬로미터 정도");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(500.0, Some("KRW"), Precision::Exact), "500원");
     example!(v, check_finance(200.0, Some("$"), Precision::Exact), "200달러");
@@ -14,6 +21,9 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(10.0, Some("INR"), Precision::Approximate), "약 10루피");
     example!(v, check_finance(2000.0, Some("$"), Precision::Approximate), "2천 달러쯤");
     example!(v, check_finance(10.0, Some("£"), Precision::Exact), "딱 10파운드");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "50달러 이하", "최대 50달러");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "100유로 이상", "최소 100유로");
+    example!(v, check_finance_interval(Some(20.0), Some(30.0), Some("$")), "20달러에서 30달러까지", "20달러와 30달러 사이", "20~30달러");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -26,10 +36,18 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(3.0, Some("degree")), "영상 삼도");
     example!(v, check_temperature(6.0, Some("celsius")), "섭씨 6도");
     example!(v, check_temperature(32.0, Some("fahrenheit")), "화씨 32도");
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "18도에서 21도까지", "18도와 21도 사이");
+    example!(v, check_temperature_interval(None, Some(5.0), false, true, Some("degree")), "5도 이하");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "5도 미만");
+    example!(v, check_temperature_interval(Some(30.0), None, true, false, Some("degree")), "30도 이상");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("degree")), "30도 초과");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300켈빈");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "2도 높게");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "3도 낮게");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "방금", "지금");
     example!(v, check_moment!(c, [2013, 2, 12]), "오늘");
     example!(v, check_moment!(c, [2013, 2, 11]), "어제");
@@ -312,4 +330,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼");
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "3에서 5까지", "3부터 5까지", "3~5");
+    example!(v, check_number_range(Some(5.0), None, true, false), "5 이상", "최소 5");
+    example!(v, check_number_range(Some(5.0), None, false, false), "5 초과");
+    example!(v, check_number_range(None, Some(10.0), false, true), "10 이하", "최대 10");
+    example!(v, check_number_range(None, Some(10.0), false, false), "10 미만");
 }
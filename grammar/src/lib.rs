@@ -1,28 +1,41 @@
 extern crate rustling;
 extern crate rustling_ontology_values;
+#[cfg(feature="lang-de")]
 extern crate rustling_ontology_de as de;
+#[cfg(feature="lang-en")]
 extern crate rustling_ontology_en as en;
+#[cfg(feature="lang-es")]
 extern crate rustling_ontology_es as es;
+#[cfg(feature="lang-fr")]
 extern crate rustling_ontology_fr as fr;
+#[cfg(feature="lang-ja")]
 extern crate rustling_ontology_ja as ja;
+#[cfg(feature="lang-ko")]
 extern crate rustling_ontology_ko as ko;
+#[cfg(feature="lang-zh")]
 extern crate rustling_ontology_zh as zh;
 
 use std::result;
 
+#[cfg(not(any(feature="lang-de", feature="lang-en", feature="lang-es", feature="lang-fr",
+              feature="lang-ja", feature="lang-ko", feature="lang-zh")))]
+compile_error!("At least one language must be enabled with the `lang-*` cargo features");
+
 macro_rules! lang_enum {
-    ([$($lang:ident),*]) => {
+    ([$($lang:ident => $feature:tt),*]) => {
         /// Enumerates all language supported for the general purpose ontology.
+        ///
+        /// Only the languages enabled by the `lang-*` cargo features are available.
         #[derive(Copy,Clone,Debug,PartialEq, Eq)]
         pub enum Lang {
-            $( $lang, )*
+            $( #[cfg(feature=$feature)] $lang, )*
         }
 
         impl Lang {
             pub fn all() -> Vec<Lang> {
-                vec![
-                    $( Lang::$lang, )*
-                ]
+                let mut langs = vec![];
+                $( #[cfg(feature=$feature)] langs.push(Lang::$lang); )*
+                langs
             }
         }
 
@@ -30,7 +43,7 @@ macro_rules! lang_enum {
             type Err = String;
             fn from_str(it: &str) -> result::Result<Lang, Self::Err> {
                 match &*it.to_uppercase() {
-                    $( stringify!($lang) => Ok(Lang::$lang),  )*
+                    $( #[cfg(feature=$feature)] stringify!($lang) => Ok(Lang::$lang),  )*
                     _ => Err(format!("Unknown language {}", it)),
                 }
             }
@@ -39,7 +52,7 @@ macro_rules! lang_enum {
         impl ::std::string::ToString for Lang {
             fn to_string(&self) -> String {
                 match self {
-                    $( &Lang::$lang => stringify!($lang).to_string(),)*
+                    $( #[cfg(feature=$feature)] &Lang::$lang => stringify!($lang).to_string(),)*
                 }
             }
         }
@@ -47,18 +60,31 @@ macro_rules! lang_enum {
     }
 }
 
-lang_enum!([DE, EN, ES, FR, JA, KO, ZH]);
+lang_enum!([DE => "lang-de",
+            EN => "lang-en",
+            ES => "lang-es",
+            FR => "lang-fr",
+            JA => "lang-ja",
+            KO => "lang-ko",
+            ZH => "lang-zh"]);
 
 
 /// Obtain rules for a given language.
 pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     match lang {
+        #[cfg(feature="lang-de")]
         Lang::DE => de::rule_set(),
+        #[cfg(feature="lang-en")]
         Lang::EN => en::rule_set(),
+        #[cfg(feature="lang-es")]
         Lang::ES => es::rule_set(),
+        #[cfg(feature="lang-fr")]
         Lang::FR => fr::rule_set(),
+        #[cfg(feature="lang-ja")]
         Lang::JA => ja::rule_set(),
+        #[cfg(feature="lang-ko")]
         Lang::KO => ko::rule_set(),
+        #[cfg(feature="lang-zh")]
         Lang::ZH => zh::rule_set(),
     }
 }
@@ -66,12 +92,19 @@ pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustl
 /// Obtain dimensions for a given language.
 pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
     match lang {
+        #[cfg(feature="lang-de")]
         Lang::DE => de::dims(),
+        #[cfg(feature="lang-en")]
         Lang::EN => en::dims(),
+        #[cfg(feature="lang-es")]
         Lang::ES => es::dims(),
+        #[cfg(feature="lang-fr")]
         Lang::FR => fr::dims(),
+        #[cfg(feature="lang-ja")]
         Lang::JA => ja::dims(),
+        #[cfg(feature="lang-ko")]
         Lang::KO => ko::dims(),
+        #[cfg(feature="lang-zh")]
         Lang::ZH => zh::dims(),
     }
 }
@@ -79,12 +112,19 @@ pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
 /// Obtain examples for a given language.
 pub fn examples(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     match lang {
+        #[cfg(feature="lang-de")]
         Lang::DE => de::examples(),
+        #[cfg(feature="lang-en")]
         Lang::EN => en::examples(),
+        #[cfg(feature="lang-es")]
         Lang::ES => es::examples(),
+        #[cfg(feature="lang-fr")]
         Lang::FR => fr::examples(),
+        #[cfg(feature="lang-ja")]
         Lang::JA => ja::examples(),
+        #[cfg(feature="lang-ko")]
         Lang::KO => ko::examples(),
+        #[cfg(feature="lang-zh")]
         Lang::ZH => zh::examples(),
     }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PartOfDay, PeriodComp};
 
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -278,8 +278,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("morning",
                       b.reg(r#"早上|早晨|朝頭?早"#)?,
                       |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(12, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Morning, 4, 12)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Morning)))
                       }
@@ -339,8 +338,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("evening|night",
                       b.reg(r#"晚上|晚间"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Evening, 18, 0)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Evening)))
                       }
@@ -515,8 +513,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("afternoon",
                       b.reg(r#"下午|中午|晏晝"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(19, false)?, false)?
+                          Ok(helpers::part_of_day(PartOfDay::Afternoon, 12, 19)?
                               .latent()
                               .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
                       }
@@ -683,6 +680,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     delta: false,
                  })
              });
 
@@ -694,6 +692,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     delta: false,
                  })
              });
 
@@ -705,6 +704,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              });
 
@@ -717,6 +717,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: b.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     delta: false,
                  })
              }
     );
@@ -729,6 +730,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     delta: false,
                  })
              });
 
@@ -741,10 +743,168 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: b.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     delta: false,
                  })
              }
     );
 
+    b.rule_2("<temp> Kelvin",
+             temperature_check!(),
+             b.reg(r#"开尔文|開爾文|°k"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some("kelvin"),
+                     latent: false,
+                     delta: false,
+                 })
+             });
+    b.rule_2("零下 <temp>",
+             b.reg(r#"零下|负|負"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0 && !temp.delta),
+             |_, a| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_4("<temp>和<temp>之间",
+             temperature_check!(),
+             b.reg(r#"和|与|與|跟"#)?,
+             temperature_check!(),
+             b.reg(r#"之间|之間"#)?,
+             |a, _, b, _| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_3("<temp>到<temp>",
+             temperature_check!(),
+             b.reg(r#"到|至|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_4("从<temp>到<temp>",
+             b.reg(r#"从|從"#)?,
+             temperature_check!(),
+             b.reg(r#"到|至"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_between(a.value(), b.value())
+    );
+    b.rule_2("<temp>以上",
+             temperature_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After, true)
+    );
+    b.rule_2("超过<temp>",
+             b.reg(r#"超过|超過|高于|高於"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After, false)
+    );
+    b.rule_2("<temp>以下",
+             temperature_check!(),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("低于<temp>",
+             b.reg(r#"低于|低於|不到"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, false)
+    );
+    b.rule_2("不超过<temp>",
+             b.reg(r#"不超过|不超過"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before, true)
+    );
+    b.rule_2("调高<temp>",
+             b.reg(r#"升高|调高|調高|提高"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_delta(a.value(), 1.0)
+    );
+    b.rule_2("调低<temp>",
+             b.reg(r#"降低|调低|調低"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_delta(a.value(), -1.0)
+    );
+    Ok(())
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as distance",
+             number_check!(),
+             |a| {
+                 Ok(DistanceValue {
+                     value: a.value().value(),
+                     unit: None,
+                     precision: Precision::Exact,
+                     latent: true,
+                 })
+             });
+    b.rule_2("<latent distance> km",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"公里|千米|km"#)?,
+             |a, _| Ok(a.value().unit("kilometre"))
+    );
+    b.rule_2("<latent distance> m",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"米|公尺|m"#)?,
+             |a, _| Ok(a.value().unit("metre"))
+    );
+    b.rule_2("<latent distance> cm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"厘米|公分|cm"#)?,
+             |a, _| Ok(a.value().unit("centimetre"))
+    );
+    b.rule_2("<latent distance> mm",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"毫米|mm"#)?,
+             |a, _| Ok(a.value().unit("millimetre"))
+    );
+    b.rule_2("<latent distance> 英里",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"英里"#)?,
+             |a, _| Ok(a.value().unit("mile"))
+    );
+    b.rule_2("<latent distance> 英尺",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"英尺|呎"#)?,
+             |a, _| Ok(a.value().unit("foot"))
+    );
+    b.rule_2("<latent distance> 英寸",
+             distance_check!(|distance: &DistanceValue| distance.latent),
+             b.reg(r#"英寸|吋"#)?,
+             |a, _| Ok(a.value().unit("inch"))
+    );
+    b.rule_2("<distance> <distance>",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |a, b| helpers::compose_distances(a.value(), b.value())
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"大约|大概|约"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<distance> about",
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             b.reg(r#"左右"#)?,
+             |a, _| {
+                 Ok(DistanceValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(r#"正好|刚好"#)?,
+             distance_check!(|distance: &DistanceValue| !distance.latent),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -910,5 +1070,55 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
 
+    b.rule_4("<number>和<number>之间",
+             number_check!(),
+             b.reg(r#"和|与|與|跟"#)?,
+             number_check!(),
+             b.reg(r#"之间|之間"#)?,
+             |a, _, b, _| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_3("<number>到<number>",
+             number_check!(),
+             b.reg(r#"到|至|~|-"#)?,
+             number_check!(),
+             |a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_4("从<number>到<number>",
+             b.reg(r#"从|從"#)?,
+             number_check!(),
+             b.reg(r#"到|至"#)?,
+             number_check!(),
+             |_, a, _, b| helpers::number_range_between(a.value(), b.value())
+    );
+    b.rule_2("至少<number>",
+             b.reg(r#"至少|最少|不少于|不少於"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("<number>以上",
+             number_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::number_range_from(a.value(), true)
+    );
+    b.rule_2("超过<number>",
+             b.reg(r#"超过|超過|多于|多於|大于|大於"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_from(a.value(), false)
+    );
+    b.rule_2("最多<number>",
+             b.reg(r#"最多|至多|不超过|不超過"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("<number>以下",
+             number_check!(),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::number_range_to(a.value(), true)
+    );
+    b.rule_2("少于<number>",
+             b.reg(r#"少于|少於|不到|小于|小於|低于|低於"#)?,
+             number_check!(),
+             |_, a| helpers::number_range_to(a.value(), false)
+    );
     Ok(())
 }
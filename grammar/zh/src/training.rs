@@ -3,6 +3,13 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("kilometre"), Precision::Exact), "5公里", "五公里", "5千米");
+    example!(v, check_distance(200.0, Some("metre"), Precision::Exact), "200米");
+    example!(v, check_distance(3.0, Some("mile"), Precision::Exact), "3英里");
+    example!(v, check_distance(10.0, Some("kilometre"), Precision::Approximate), "大约10公里", "10公里左右");
+}
+
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 0, 1]), "1 秒钟", "一 秒鐘", "一 秒");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1]), "1 分鐘", "一 分鐘");
@@ -13,7 +20,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 10]), "前天", "前日", "上周日", "上星期天", "上礼拜天", "上週日", "上星期天", "上禮拜天", "上禮拜日");
     example!(v, check_moment!(c, [2013, 2, 10]), "周日, 二月十号", "星期天, 二月十号", "礼拜天, 二月十号", "週日, 二月十號", "星期天, 二月十號", "禮拜天, 二月十號", "禮拜日, 二月十號");
     example!(v, check_moment!(c, [2013, 2, 13]),"星期三", "周三", "礼拜三", "禮拜三", "週三", "明天", "明日", "聽日");
@@ -147,6 +154,15 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
              "37攝氏°",
              "37摄氏度",
              "37攝氏度");
+    example!(v, check_temperature_interval(Some(18.0), Some(21.0), true, true, Some("degree")), "18到21度", "18度到21度", "从18度到21度");
+    example!(v, check_temperature_interval(None, Some(5.0), false, true, Some("degree")), "5度以下", "不超过5度");
+    example!(v, check_temperature_interval(None, Some(5.0), false, false, Some("degree")), "低于5度");
+    example!(v, check_temperature_interval(Some(30.0), None, true, false, Some("degree")), "30度以上");
+    example!(v, check_temperature_interval(Some(30.0), None, false, false, Some("degree")), "超过30度");
+    example!(v, check_temperature(-5.0, Some("degree")), "零下5度");
+    example!(v, check_temperature(300.0, Some("kelvin")), "300开尔文");
+    example!(v, check_temperature_delta(2.0, Some("degree")), "调高2度");
+    example!(v, check_temperature_delta(-3.0, Some("degree")), "调低3度");
 }
 
 
@@ -190,4 +206,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(7), "第七");
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
+    example!(v, check_number_range(Some(3.0), Some(5.0), true, true), "3到5", "从3到5", "3和5之间", "3至5");
+    example!(v, check_number_range(Some(5.0), None, true, false), "至少5", "5以上");
+    example!(v, check_number_range(Some(5.0), None, false, false), "超过5");
+    example!(v, check_number_range(None, Some(10.0), false, true), "最多10", "10以下");
+    example!(v, check_number_range(None, Some(10.0), false, false), "少于10", "不到10");
 }
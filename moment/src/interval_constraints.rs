@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use bidirectional_walker::*;
 use walker::*;
@@ -6,21 +6,145 @@ use {Moment, Interval, last_day_in_month};
 use period::*;
 use std::ops;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use chrono::offset::local::Local;
-use chrono::{Datelike, TimeZone, Timelike, Weekday};
+use chrono::{Datelike, NaiveDate, TimeZone, Timelike, Weekday};
 
 #[derive(Clone, PartialEq, new)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    /// First day of the weeks walked by `Cycle(Week)`
+    #[new(value = "Weekday::Mon")]
+    pub week_start: Weekday,
+    #[new(default)]
+    pub part_of_day_hours: PartOfDayHours,
+    /// Reading of the numeric dates, the one of the grammar when not set
+    #[new(default)]
+    pub date_order: Option<DateOrder>,
+    #[new(value = "Hemisphere::Northern")]
+    pub hemisphere: Hemisphere,
+    #[new(value = "SeasonCalendar::Astronomical")]
+    pub season_calendar: SeasonCalendar,
+    /// Days off skipped by the business days
+    #[new(default)]
+    pub business_calendar: BusinessCalendar,
 }
 
-impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
-
 impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?} }}", self.reference, self.min, self.max)
+        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?}, week_start: {:?}, part_of_day_hours: {:?}, date_order: {:?}, hemisphere: {:?}, season_calendar: {:?}, business_calendar: {:?} }}",
+               self.reference, self.min, self.max, self.week_start, self.part_of_day_hours, self.date_order, self.hemisphere, self.season_calendar, self.business_calendar)
+    }
+}
+
+/// Days that are not business days: the weekend days, saturday and sunday by default, and a list
+/// of holidays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessCalendar {
+    weekend: [bool; 7],
+    holidays: Arc<[NaiveDate]>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar::new(&[Weekday::Sat, Weekday::Sun], vec![])
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new(weekend: &[Weekday], holidays: Vec<NaiveDate>) -> BusinessCalendar {
+        BusinessCalendar { weekend: [false; 7], holidays: Arc::from(vec![]) }
+            .with_weekend(weekend)
+            .with_holidays(holidays)
+    }
+
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> BusinessCalendar {
+        self.weekend = [false; 7];
+        for day in weekend {
+            self.weekend[day.num_days_from_monday() as usize] = true;
+        }
+        self
+    }
+
+    pub fn with_holidays(mut self, mut holidays: Vec<NaiveDate>) -> BusinessCalendar {
+        holidays.sort();
+        holidays.dedup();
+        self.holidays = Arc::from(holidays);
+        self
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.weekend[date.weekday().num_days_from_monday() as usize]
+            && self.holidays.binary_search(&date).is_err()
+    }
+
+    /// Whether some days of the week are business days, there are none when the whole week is
+    /// weekend.
+    fn has_business_days(&self) -> bool {
+        self.weekend.iter().any(|weekend| !weekend)
+    }
+}
+
+/// Order of the month and the day in numeric dates like "3/4/2015".
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    DayFirst,
+    MonthFirst,
+    /// Both readings, in chronological order.
+    Both,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// Hemisphere of the seasons, summer being in december in the southern one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Boundaries of the seasons.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SeasonCalendar {
+    /// Seasons starting at the solstices and the equinoxes, summer from june 21 to september 23.
+    Astronomical,
+    /// Seasons of three whole months, summer from june to august.
+    Meteorological,
+}
+
+/// Parts of the day and meals whose hours depend on the locale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PartOfDay {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+    Breakfast,
+    Brunch,
+    Lunch,
+    Dinner,
+}
+
+/// Hours of the parts of the day set in a context, as `(start, end)` hours of a 24h clock, the
+/// end being excluded. Parts of the day without hours keep the ones of the grammar.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PartOfDayHours([Option<(u32, u32)>; 8]);
+
+impl PartOfDayHours {
+    pub fn get(&self, part: PartOfDay) -> Option<(u32, u32)> {
+        self.0[part as usize]
+    }
+
+    pub fn set(&mut self, part: PartOfDay, start: u32, end: u32) {
+        self.0[part as usize] = Some((start, end));
     }
 }
 
@@ -36,38 +160,124 @@ impl Context<Local> {
     }
 }
 
+/// Bounds of the years of the search window, kept away from the limits of chrono so that the
+/// walkers can step a bit past the window.
+const MIN_YEAR: i32 = -262_000;
+const MAX_YEAR: i32 = 262_000;
+
 impl<T: TimeZone> Context<T> where <T as TimeZone>::Offset: Copy {
+    /// Context searching the whole range of dates supported by chrono, `with_window` narrows it.
     pub fn for_reference(now: Interval<T>) -> Context<T> {
-        // TODO: Should be refactor with the min, max date offer by chrono crate
-        let now_end = now.end_moment();
-        let max_year = if 2038 > now_end.year() + 30 { now_end.year() + 30 } else { 2038 };
-        let min_year = if 1970 < now.start.year() - 30 { now.start.year() - 30  } else { 1970 };
-        let min_interval = Interval::starting_at(Moment(now.timezone().ymd(min_year, 1, 1).and_hms(0, 0, 0)), Grain::Second);
-        let max_interval = Interval::starting_at(Moment(now.timezone().ymd(max_year, 1, 1).and_hms(0, 0, 0)), Grain::Second);
-        Context::new(now, min_interval, max_interval)
+        let context = Context::new(now, now, now);
+        context.with_years(MIN_YEAR as i64, MAX_YEAR as i64)
+    }
+
+    /// Context searching from `years_before` years before the reference time to `years_after`
+    /// years after it.
+    pub fn with_window(self, years_before: u32, years_after: u32) -> Context<T> {
+        let min_year = self.reference.start.year() as i64 - years_before as i64;
+        let max_year = self.reference.end_moment().year() as i64 + years_after as i64;
+        self.with_years(min_year, max_year)
+    }
+
+    fn with_years(self, min_year: i64, max_year: i64) -> Context<T> {
+        let year = |y: i64| ::std::cmp::max(MIN_YEAR as i64, ::std::cmp::min(MAX_YEAR as i64, y)) as i32;
+        let (min_year, max_year) = (year(min_year), year(max_year));
+        let timezone = self.reference.timezone();
+        Context {
+            min: Interval::starting_at(Moment(timezone.ymd(min_year, 1, 1).and_hms(0, 0, 0)), Grain::Second),
+            max: Interval::starting_at(Moment(timezone.ymd(max_year, 1, 1).and_hms(0, 0, 0)), Grain::Second),
+            .. self
+        }
     }
 }
 
 pub type IntervalWalker<T> = BidirectionalWalker<Interval<T>>;
 
-pub trait IntervalConstraint<T: TimeZone> where <T as TimeZone>::Offset: Copy {
+/// A constraint on time intervals.
+///
+/// Constraints are compared and hashed through their description. The `Debug` output of a
+/// constraint is a readable form of it (e.g. `Intersect(DayOfWeek(Mon), Hour(3, 12h))`).
+pub trait IntervalConstraint<T: TimeZone>: fmt::Debug where <T as TimeZone>::Offset: Copy {
     fn grain(&self) -> Grain;
     fn coarse_grain_step(&self) -> Grain;
+    fn describe(&self) -> Description<T>;
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T>;
 }
 
+/// Kind of a constraint with its parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConstraintKind<'a> {
+    Year(i32),
+    YearMonthDay(i32, u32, u32),
+    MonthDay(u32, u32),
+    YearPart(Season),
+    BusinessDay,
+    NthBusinessDay(i64),
+    NumericDate(u32, u32, Option<i32>, DateOrder),
+    Month(u32),
+    DayOfMonth(u32),
+    /// Day of the week, numbered from monday
+    DayOfWeek(u32),
+    HourMinute(u32, u32, bool),
+    Hour(u32, bool),
+    DayPart(PartOfDay, u32, u32),
+    Minute(u32),
+    Second(u32),
+    Cycle(Grain),
+    TakeTheNth(i64, bool),
+    TakeN(i64, bool),
+    TakeTheNthAfter(i64, bool),
+    TakeLastOf,
+    Intersection,
+    /// Name and parameters of the offset
+    Translate(&'static str, &'a [i64]),
+    Span(bool),
+    /// Quantity of each grain of the period
+    ShiftBy([i64; 8]),
+}
+
+/// Structural description of a constraint: its kind and the constraints it is built on. Two
+/// constraints with the same description walk the same intervals.
+pub type Description<'a, T> = (ConstraintKind<'a>, Option<&'a RcConstraint<T>>, Option<&'a RcConstraint<T>>);
+
+/// Shared handle on a constraint. Constraints are immutable and thread safe, so a constraint
+/// tree can be shared between threads.
 #[derive(Clone)]
-pub struct RcConstraint<T: TimeZone>(pub Rc<IntervalConstraint<T>>);
+pub struct RcConstraint<T: TimeZone>(pub Arc<IntervalConstraint<T> + Send + Sync>);
+
+/// Offset function used by `Translate`.
+pub type OffsetFn<T> = Arc<Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + Send + Sync>;
 
 impl<T: TimeZone> ops::Deref for RcConstraint<T> where <T as TimeZone>::Offset: Copy {
-    type Target = Rc<IntervalConstraint<T>>;
+    type Target = Arc<IntervalConstraint<T> + Send + Sync>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
+impl<T: TimeZone> fmt::Debug for RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl<T: TimeZone> PartialEq for RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+    fn eq(&self, other: &RcConstraint<T>) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.describe() == other.0.describe()
+    }
+}
+
+impl<T: TimeZone> Eq for RcConstraint<T> where <T as TimeZone>::Offset: Copy {}
+
+impl<T: TimeZone> Hash for RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.describe().hash(state)
+    }
+}
+
 macro_rules! rc {
-    ($obj:expr) => (RcConstraint(Rc::new($obj)))
+    ($obj:expr) => (RcConstraint(Arc::new($obj)))
 }
 
 
@@ -76,9 +286,12 @@ impl<T: TimeZone+'static> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
         ShiftBy::new(self, period)
     }
 
-    pub fn translate_with<Offset>(&self, offset: Offset) -> RcConstraint<T>
-        where Offset: Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + 'static {
-        Translate::new(self, Rc::new(offset))
+    /// Translates the intervals of the constraint with `offset`. `name` and `params` identify the
+    /// offset in the description of the constraint, offsets with the same name and parameters
+    /// must compute the same translation.
+    pub fn translate_with<Offset>(&self, name: &'static str, params: &[i64], offset: Offset) -> RcConstraint<T>
+        where Offset: Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + Send + Sync + 'static {
+        Translate::new(self, name, params.to_vec(), Arc::new(offset))
     }
 
     pub fn take_the_nth(&self, n: i64) -> RcConstraint<T> {
@@ -116,6 +329,22 @@ impl<T: TimeZone+'static> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
     pub fn the_nth(&self, n: i64) -> NthConstraint<T> {
         NthConstraint(self.clone(), n)
     }
+
+    /// Intervals of the constraint intersecting `window`, in chronological order. The intervals
+    /// starting before the window are looked up eagerly, the others are generated lazily.
+    pub fn walk_within(&self, window: Interval<T>, context: &Context<T>) -> Walker<Interval<T>> {
+        let walker = self.to_walker(&window, context);
+        let window_end = window.end_moment();
+        let mut before = walker.backward
+            .take_while(move |interval| interval.intersect(window).is_some())
+            .into_iter()
+            .collect::<Vec<_>>();
+        before.reverse();
+        let within = walker.forward
+            .skip_while(move |interval| interval.end_moment() <= window.start)
+            .take_while(move |interval| interval.start < window_end);
+        Walker::vec(before).chain(&within)
+    }
 }
 
 
@@ -137,6 +366,10 @@ impl<T: TimeZone> IntervalConstraint<T> for Year where <T as TimeZone>::Offset:
         Grain::Year
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Year(self.0), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let normalized_year = if self.0 <= 99 {
             (self.0 + 50) % 100 + 2000 - 50
@@ -156,13 +389,19 @@ impl<T: TimeZone> IntervalConstraint<T> for Year where <T as TimeZone>::Offset:
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct YearMonthDay {
     pub year: i32, 
     pub month: u32, 
     pub day: u32,
 }
 
+impl fmt::Debug for YearMonthDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "YearMonthDay({}, {}, {})", self.year, self.month, self.day)
+    }
+}
+
 impl YearMonthDay {
     pub fn new<T: TimeZone>(y: i32, m: u32, d: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
         rc!(YearMonthDay { year: y, month: m, day: d })
@@ -178,6 +417,10 @@ impl<T: TimeZone> IntervalConstraint<T> for YearMonthDay where <T as TimeZone>::
         Grain::Year
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::YearMonthDay(self.year, self.month, self.day), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let normalized_year = if self.year < 99 {
             (self.year + 50) % 100 + 2000 - 50
@@ -217,6 +460,10 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay where <T as TimeZ
         Grain::Year
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::MonthDay(self.0, self.1), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let rounded_moment = Moment(origin.timezone()
                                         .ymd(origin.start.year(), self.0, 1)
@@ -251,6 +498,237 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay where <T as TimeZ
 }
 
 
+/// Season of the year, with the hemisphere and the calendar of the context.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct YearPart(pub Season);
+
+impl YearPart {
+    pub fn new<T: TimeZone>(season: Season) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(YearPart(season))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for YearPart where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::YearPart(self.0), None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let season = match (context.hemisphere, self.0) {
+            (Hemisphere::Northern, season) => season,
+            (Hemisphere::Southern, Season::Spring) => Season::Autumn,
+            (Hemisphere::Southern, Season::Summer) => Season::Winter,
+            (Hemisphere::Southern, Season::Autumn) => Season::Spring,
+            (Hemisphere::Southern, Season::Winter) => Season::Summer,
+        };
+        let constraint: RcConstraint<T> = match context.season_calendar {
+            SeasonCalendar::Astronomical => {
+                let (start, end) = match season {
+                    Season::Spring => ((3, 20), (6, 21)),
+                    Season::Summer => ((6, 21), (9, 23)),
+                    Season::Autumn => ((9, 23), (12, 21)),
+                    Season::Winter => ((12, 21), (3, 20)),
+                };
+                rc!(MonthDay(start.0, start.1)).span_inclusive_to(&rc!(MonthDay(end.0, end.1)))
+            }
+            SeasonCalendar::Meteorological => {
+                let (start, end) = match season {
+                    Season::Spring => (3, 6),
+                    Season::Summer => (6, 9),
+                    Season::Autumn => (9, 12),
+                    Season::Winter => (12, 3),
+                };
+                rc!(Month(start)).span_to(&rc!(Month(end)))
+            }
+        };
+        constraint.to_walker(origin, context)
+    }
+}
+
+
+/// Business days, the days that are neither weekend days nor holidays in the business calendar
+/// of the context.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BusinessDay;
+
+impl BusinessDay {
+    pub fn rc<T: TimeZone>() -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(BusinessDay)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for BusinessDay where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::BusinessDay, None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        if !context.business_calendar.has_business_days() {
+            return BidirectionalWalker::new();
+        }
+        let anchor = origin.start_round_to(Grain::Day);
+        let days = BidirectionalWalker::new()
+            .forward_with(anchor, |prev| prev + PeriodComp::days(1))
+            .backward_with(anchor - PeriodComp::days(1), |prev| prev - PeriodComp::days(1));
+        let calendar = context.business_calendar.clone();
+        let forward = days.forward.filter(move |day| calendar.is_business_day(naive_date(day)));
+        let calendar = context.business_calendar.clone();
+        let backward = days.backward.filter(move |day| calendar.is_business_day(naive_date(day)));
+        BidirectionalWalker::new().forward(forward).backward(backward)
+    }
+}
+
+fn naive_date<T: TimeZone>(interval: &Interval<T>) -> NaiveDate {
+    NaiveDate::from_ymd(interval.start.year(), interval.start.month(), interval.start.day())
+}
+
+
+/// The day `n` business days after the day of the reference time, "in 3 business days", or
+/// before it when `n` is negative. With `n` being 0, the day of the reference time if it is a
+/// business day, or else the next business day.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NthBusinessDay(pub i64);
+
+impl NthBusinessDay {
+    pub fn new<T: TimeZone>(n: i64) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(NthBusinessDay(n))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for NthBusinessDay where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::NthBusinessDay(self.0), None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let reference_day = context.reference.start_round_to(Grain::Day);
+        let walker = BusinessDay.to_walker(&reference_day, context);
+        let day = if self.0 > 0 {
+            walker.forward
+                .skip_while(move |day| day.start == reference_day.start)
+                .skip((self.0 - 1) as usize)
+                .next()
+        } else if self.0 == 0 {
+            walker.forward.clone().next()
+        } else {
+            walker.backward.skip((-(self.0 + 1)) as usize).next()
+        };
+
+        match day {
+            Some(day) if origin.start < day.end_moment() => BidirectionalWalker::new().forward_values(vec![day]),
+            Some(day) => BidirectionalWalker::new().backward_values(vec![day]),
+            None => BidirectionalWalker::new(),
+        }
+    }
+}
+
+
+/// Numeric date like "3/4" or "3/4/2015", read with the date order of the context or else the
+/// one of the grammar. A reading that is not a valid date is never used.
+#[derive(Copy, Clone, PartialEq)]
+pub struct NumericDate {
+    pub first: u32,
+    pub second: u32,
+    pub year: Option<i32>,
+    pub order: DateOrder,
+}
+
+impl fmt::Debug for NumericDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "NumericDate({}, {}, {}, {:?})", self.first, self.second, year, self.order),
+            None => write!(f, "NumericDate({}, {}, {:?})", self.first, self.second, self.order),
+        }
+    }
+}
+
+impl NumericDate {
+    pub fn new<T: TimeZone>(first: u32, second: u32, year: Option<i32>, order: DateOrder) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(NumericDate {
+            first: first,
+            second: second,
+            year: year,
+            order: order,
+        })
+    }
+
+    /// Valid `(month, day)` readings of the date with `order`. Only `Both` gives the two
+    /// readings, month first then day first, "13/4" has none with `MonthFirst`.
+    pub fn readings(&self, order: DateOrder) -> Vec<(u32, u32)> {
+        let month_first = (self.first, self.second);
+        let day_first = (self.second, self.first);
+        let mut readings = match order {
+            DateOrder::MonthFirst => vec![month_first],
+            DateOrder::DayFirst => vec![day_first],
+            DateOrder::Both => vec![month_first, day_first],
+        };
+        readings.retain(|&(m, d)| m >= 1 && m <= 12 && d >= 1 && d <= [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][m as usize - 1]);
+        readings.dedup();
+        readings
+    }
+
+    fn reading<T: TimeZone>(&self, month: u32, day: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        match self.year {
+            Some(year) => YearMonthDay::new(year, month, day),
+            None => rc!(MonthDay(month, day)),
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for NumericDate where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::NumericDate(self.first, self.second, self.year, self.order), None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let mut walkers = self.readings(context.date_order.unwrap_or(self.order))
+            .into_iter()
+            .map(|(month, day)| self.reading::<T>(month, day).to_walker(origin, context));
+        match (walkers.next(), walkers.next()) {
+            (Some(lhs), Some(rhs)) => {
+                BidirectionalWalker::new()
+                    .forward(lhs.forward.merge(&rhs.forward, |a, b| a.start <= b.start))
+                    .backward(lhs.backward.merge(&rhs.backward, |a, b| a.start >= b.start))
+            }
+            (Some(walker), None) => walker,
+            _ => BidirectionalWalker::new(),
+        }
+    }
+}
+
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Month(pub u32);
 
@@ -269,6 +747,10 @@ impl<T: TimeZone> IntervalConstraint<T> for Month where <T as TimeZone>::Offset:
         Grain::Year
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Month(self.0), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let rounded_moment = Moment(origin.timezone()
                                         .ymd(origin.start.year(), self.0, 1)
@@ -303,6 +785,10 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for DayOfMonth where <T as Tim
         Grain::Month
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::DayOfMonth(self.0), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let offset_month = (origin.start.0.day() > self.0) as i64;
         let anchor = origin.start_round_to(Grain::Month) + PeriodComp::months(offset_month);
@@ -350,6 +836,10 @@ impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek where <T as TimeZone>::Off
         Grain::Week
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::DayOfWeek(self.0.num_days_from_monday()), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         // number_from_monday is u32 -> use i64
         let offset = (self.0.number_from_monday() as i64 -
@@ -364,13 +854,19 @@ impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek where <T as TimeZone>::Off
 }
 
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct HourMinute {
     pub hour: u32,
     pub minute: u32,
     pub is_12_clock: bool,
 }
 
+impl fmt::Debug for HourMinute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HourMinute({}, {}, {})", self.hour, self.minute, if self.is_12_clock { "12h" } else { "24h" })
+    }
+}
+
 impl HourMinute {
     pub fn clock_12<T: TimeZone>(hour: u32, minute: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
         rc!(HourMinute {
@@ -399,6 +895,10 @@ impl<T: TimeZone> IntervalConstraint<T> for HourMinute where <T as TimeZone>::Of
         Grain::Day
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::HourMinute(self.hour, self.minute, self.is_12_clock), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let clock_step = if self.hour <= 12 && self.is_12_clock {
             12
@@ -416,12 +916,18 @@ impl<T: TimeZone> IntervalConstraint<T> for HourMinute where <T as TimeZone>::Of
 }
 
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct Hour {
     pub quantity: u32,
     pub is_12_clock: bool,
 }
 
+impl fmt::Debug for Hour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Hour({}, {})", self.quantity, if self.is_12_clock { "12h" } else { "24h" })
+    }
+}
+
 impl Hour {
     pub fn clock_12<T: TimeZone>(quantity: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
         rc!(Hour {
@@ -447,6 +953,10 @@ impl<T: TimeZone> IntervalConstraint<T> for Hour where <T as TimeZone>::Offset:
         Grain::Day
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Hour(self.quantity, self.is_12_clock), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let clock_step = if self.quantity <= 12 && self.is_12_clock {
             12
@@ -464,6 +974,51 @@ impl<T: TimeZone> IntervalConstraint<T> for Hour where <T as TimeZone>::Offset:
 }
 
 
+/// Hours of a part of the day, the ones given by the grammar unless the context overrides
+/// them.
+#[derive(Copy, Clone, PartialEq)]
+pub struct DayPart {
+    pub part: PartOfDay,
+    pub hours: (u32, u32),
+}
+
+impl fmt::Debug for DayPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DayPart({:?}, {}-{})", self.part, self.hours.0, self.hours.1)
+    }
+}
+
+impl DayPart {
+    pub fn new<T: TimeZone>(part: PartOfDay, start: u32, end: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(DayPart {
+            part: part,
+            hours: (start, end),
+        })
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for DayPart where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::DayPart(self.part, self.hours.0, self.hours.1), None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (start, end) = context.part_of_day_hours.get(self.part).unwrap_or(self.hours);
+        Hour::clock_24(start)
+            .span_to(&Hour::clock_24(end))
+            .to_walker(origin, context)
+    }
+}
+
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Minute(pub u32);
 
@@ -482,6 +1037,10 @@ impl<T: TimeZone> IntervalConstraint<T> for Minute where <T as TimeZone>::Offset
         Grain::Hour
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Minute(self.0), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let offset = (self.0 as i64 - origin.start.minute() as i64) % 60;
         let anchor = origin.start_round_to(Grain::Minute) + PeriodComp::minutes(offset);
@@ -511,6 +1070,10 @@ impl<T: TimeZone> IntervalConstraint<T> for Second where <T as TimeZone>::Offset
         Grain::Minute
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Second(self.0), None, None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         let offset = (self.0 as i64 - origin.start.second() as i64 + 60) % 60;
         let anchor = origin.start_round_to(Grain::Second) + PeriodComp::seconds(offset);
@@ -554,8 +1117,16 @@ impl<T: TimeZone> IntervalConstraint<T> for Cycle where <T as TimeZone>::Offset:
         self.0
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let anchor = origin.start_round_to(self.0);
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Cycle(self.0), None, None)
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let anchor = if self.0 == Grain::Week {
+            origin.start_round_to_week(context.week_start)
+        } else {
+            origin.start_round_to(self.0)
+        };
         let grain = self.0;
         BidirectionalWalker::new()
             .forward_with(anchor, move |prev| prev + PeriodComp::new(grain, 1))
@@ -572,6 +1143,12 @@ pub struct TakeTheNth<T: TimeZone> {
     inner: RcConstraint<T>,
 }
 
+impl<T: TimeZone> fmt::Debug for TakeTheNth<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TakeTheNth({}, {:?}{})", self.n, self.inner, if self.not_immediate { ", not_immediate" } else { "" })
+    }
+}
+
 impl<T: TimeZone + 'static> TakeTheNth<T> where <T as TimeZone>::Offset: Copy {
     pub fn new(n: i64, not_immediate: bool, inner: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(TakeTheNth {
@@ -591,6 +1168,10 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for TakeTheNth<T> where <T as Ti
         self.inner.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::TakeTheNth(self.n, self.not_immediate), Some(&self.inner), None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let base_interval = context.reference;
         let interval_walker = self.inner.to_walker(&base_interval, context);
@@ -635,6 +1216,12 @@ pub struct TakeN<T: TimeZone> {
     inner: RcConstraint<T>,
 }
 
+impl<T: TimeZone> fmt::Debug for TakeN<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TakeN({}, {:?}{})", self.n, self.inner, if self.not_immediate { ", not_immediate" } else { "" })
+    }
+}
+
 impl<T: TimeZone + 'static> TakeN<T> where <T as TimeZone>::Offset: Copy {
     pub fn new(n: i64, not_immediate: bool, inner: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(TakeN {
@@ -654,6 +1241,10 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeN<T> where <T as TimeZ
         self.inner.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::TakeN(self.n, self.not_immediate), Some(&self.inner), None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let base_interval = context.reference;
         let interval_walker = self.inner.to_walker(&base_interval, context);
@@ -707,6 +1298,12 @@ pub struct TakeTheNthAfter<T: TimeZone> {
     cycle: RcConstraint<T>,
 }
 
+impl<T: TimeZone> fmt::Debug for TakeTheNthAfter<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TakeTheNthAfter({}, {:?}, {:?}{})", self.n, self.cycle, self.after, if self.not_immediate { ", not_immediate" } else { "" })
+    }
+}
+
 impl<T: TimeZone + 'static> TakeTheNthAfter<T>  where <T as TimeZone>::Offset: Copy {
     pub fn new(n: i64, not_immediate: bool, after: &RcConstraint<T>, cycle: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(TakeTheNthAfter {
@@ -727,13 +1324,19 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeTheNthAfter<T>  where
         self.after.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::TakeTheNthAfter(self.n, self.not_immediate), Some(&self.after), Some(&self.cycle))
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let cycle = self.cycle.clone();
         let n = self.n;
         let not_immediate = self.not_immediate;
         let translate = Translate {
             generator: self.after.clone(),
-            offset: Rc::new(move |after: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
+            name: "nth_after",
+            params: vec![],
+            offset: Arc::new(move |after: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                 let walker = cycle.to_walker(after, c);
                 if n >= 0 {
                     let head = walker.forward.clone().next();
@@ -758,6 +1361,12 @@ pub struct TakeLastOf<T: TimeZone> {
     cycle: RcConstraint<T>,
 }
 
+impl<T: TimeZone> fmt::Debug for TakeLastOf<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TakeLastOf({:?}, {:?})", self.base, self.cycle)
+    }
+}
+
 impl<T: TimeZone+'static> TakeLastOf<T>  where <T as TimeZone>::Offset: Copy {
     pub fn new(base: &RcConstraint<T>, cycle: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(TakeLastOf {
@@ -776,11 +1385,17 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for TakeLastOf<T>  where <T as T
         self.base.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::TakeLastOf, Some(&self.base), Some(&self.cycle))
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let cycle = self.cycle.clone();
         let translate = Translate {
             generator: self.base.clone(),
-            offset: Rc::new(move |i: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
+            name: "last_of",
+            params: vec![],
+            offset: Arc::new(move |i: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                 let pivot = i.after();
                 let walker = cycle.to_walker(&pivot, c);
                 walker.backward.clone().next()
@@ -798,6 +1413,12 @@ pub struct Intersection<T: TimeZone> {
     rhs: RcConstraint<T>,
 }
 
+impl<T: TimeZone> fmt::Debug for Intersection<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Intersect({:?}, {:?})", self.lhs, self.rhs)
+    }
+}
+
 impl<T: TimeZone+'static> Intersection<T>  where <T as TimeZone>::Offset: Copy {
     fn new(lhs: &RcConstraint<T>, rhs: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(Intersection { lhs: lhs.clone(), rhs: rhs.clone() })
@@ -813,14 +1434,18 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
         ::std::cmp::min(self.lhs.grain(), self.rhs.grain())
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Intersection, Some(&self.lhs), Some(&self.rhs))
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
 
         fn walk_from<U: TimeZone+'static>(origin: &Interval<U>,
-                     context: Context<U>,
+                     context: &Context<U>,
                      constraint: RcConstraint<U>)
                      -> Walker<Interval<U>> where <U as TimeZone>::Offset: Copy 
         {
-            let context = Context::new(context.reference, *origin, *origin);
+            let context = Context { min: *origin, max: *origin, .. context.clone() };
             let max_moment = origin.end_moment();
             let origin_copied = origin.clone();
             constraint
@@ -832,31 +1457,34 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
         }
 
         fn combine<U: TimeZone+'static>(origin: &Interval<U>,
-                                 context: Context<U>,
+                                 context: &Context<U>,
                                  fine: RcConstraint<U>,
                                  coarse: RcConstraint<U>)
                                  -> IntervalWalker<U> where <U as TimeZone>::Offset: Copy
         {
-            let coarse_walker = coarse.to_walker(origin, &context);
+            let coarse_walker = coarse.to_walker(origin, context);
             let max_moment = context.max.end_moment();
+            let min_moment = context.min.start;
             let fine_for_walker = fine.clone();
+            let context_for_walker = context.clone();
             let fore = coarse_walker
                 .forward
                 .take_while(move |i| i.start <= max_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine_for_walker.clone()));
+                .flat_map(move |i| walk_from(&i, &context_for_walker, fine_for_walker.clone()));
+            let context = context.clone();
             let back = coarse_walker
                 .backward
-                .take_while(move |i| i.end_moment() >= context.min.start)
+                .take_while(move |i| i.end_moment() >= min_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine.clone()));
+                .flat_map(move |i| walk_from(&i, &context, fine.clone()));
             IntervalWalker::new().forward(fore).backward(back)
         }
 
         if self.lhs.coarse_grain_step() <= self.rhs.coarse_grain_step() {
-            combine(origin, *context, self.rhs.clone(), self.lhs.clone())
+            combine(origin, context, self.rhs.clone(), self.lhs.clone())
         } else {
-            combine(origin, *context, self.lhs.clone(), self.rhs.clone())
+            combine(origin, context, self.lhs.clone(), self.rhs.clone())
         }
     }
 }
@@ -865,15 +1493,32 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
 #[derive(Clone)]
 pub struct Translate<T: TimeZone> {
     generator: RcConstraint<T>,
-    offset: Rc<Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>>>,
+    name: &'static str,
+    params: Vec<i64>,
+    offset: OffsetFn<T>,
+}
+
+impl<T: TimeZone> fmt::Debug for Translate<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.params.is_empty() {
+            write!(f, "Translate({:?}, {})", self.generator, self.name)
+        } else {
+            let params = self.params.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+            write!(f, "Translate({:?}, {}({}))", self.generator, self.name, params.join(", "))
+        }
+    }
 }
 
 impl<T: TimeZone+'static> Translate<T>  where <T as TimeZone>::Offset: Copy {
     pub fn new(generator: &RcConstraint<T>,
-               offset: Rc<Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>>>)
+               name: &'static str,
+               params: Vec<i64>,
+               offset: OffsetFn<T>)
                -> RcConstraint<T> {
         rc!(Translate {
             generator: generator.clone(),
+            name: name,
+            params: params,
             offset: offset,
         })
     }
@@ -889,44 +1534,53 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Translate<T>  where <T as Ti
         self.generator.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Translate(self.name, &self.params), Some(&self.generator), None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let generator_walker = self.generator.to_walker(origin, context);
-        let context = *context;
+        let max_moment = context.max.end_moment();
+        let min_moment = context.min.start;
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let origin = *origin;
         let prepend_to_fore = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .take_while(move |i| origin.start <= i.end_moment());
         let mut prepend_to_fore: Vec<Interval<T>> = prepend_to_fore.into_iter().collect();
         prepend_to_fore.reverse();
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let still_fore = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .skip_while(move |i| origin.start > i.end_moment())
-            .take_while(move |i| i.start <= context.max.end_moment());
+            .take_while(move |i| i.start <= max_moment);
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let prepend_to_back = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .take_while(move |i| origin.start > i.end_moment());
         let mut prepend_to_back: Vec<Interval<T>> = prepend_to_back.into_iter().collect();
         prepend_to_back.reverse();
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let still_back = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .skip_while(move |i| origin.start <= i.end_moment())
-            .take_while(move |i| context.min.start <= i.end_moment());
+            .take_while(move |i| min_moment <= i.end_moment());
 
         IntervalWalker::new()
             .forward(Walker::vec(prepend_to_fore).chain(&still_fore))
@@ -942,6 +1596,12 @@ pub struct Span<T: TimeZone> {
     inclusive: bool,
 }
 
+impl<T: TimeZone> fmt::Debug for Span<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Span({:?}, {:?}{})", self.from, self.to, if self.inclusive { ", inclusive" } else { "" })
+    }
+}
+
 impl<T: TimeZone+'static> Span<T>  where <T as TimeZone>::Offset: Copy {
     pub fn new(from: &RcConstraint<T>, to: &RcConstraint<T>, inclusive: bool) -> RcConstraint<T> {
         rc!(Span { from: from.clone(), to: to.clone(), inclusive })
@@ -957,12 +1617,18 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Span<T>  where <T as TimeZon
         self.from.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        (ConstraintKind::Span(self.inclusive), Some(&self.from), Some(&self.to))
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let inclusive = self.inclusive;
         let to = self.to.clone();
         let translate = Translate {
             generator: self.from.clone(),
-            offset: Rc::new(move |start: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
+            name: "span_to",
+            params: vec![],
+            offset: Arc::new(move |start: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                 to.to_walker(start, c)
                     .forward
                     .next()
@@ -985,6 +1651,17 @@ pub struct ShiftBy<T: TimeZone> {
     period: Period,
 }
 
+impl<T: TimeZone> fmt::Debug for ShiftBy<T> where <T as TimeZone>::Offset: Copy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let comps = self.period.comps()
+            .into_iter()
+            .filter(|comp| comp.quantity != 0)
+            .map(|comp| format!("{} {:?}", comp.quantity, comp.grain))
+            .collect::<Vec<_>>();
+        write!(f, "ShiftBy({:?}, {})", self.base, comps.join(" "))
+    }
+}
+
 impl<T: TimeZone+'static> ShiftBy<T>  where <T as TimeZone>::Offset: Copy {
     pub fn new(base: &RcConstraint<T>, period: Period) -> RcConstraint<T> {
         rc!(ShiftBy { base:base.clone(), period })
@@ -1000,13 +1677,23 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for ShiftBy<T>  where <T as Time
         self.base.coarse_grain_step()
     }
 
+    fn describe(&self) -> Description<T> {
+        let mut comps = [0; 8];
+        for (grain, quantity) in self.period.0.iter() {
+            comps[grain] = *quantity;
+        }
+        (ConstraintKind::ShiftBy(comps), Some(&self.base), None)
+    }
+
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         if let Some(period_grain) = self.period.finer_grain() {
             let period = self.period.clone();
             let next_grain = period_grain.next();
             let translate = Translate {
                 generator: self.base.clone(),
-                offset: Rc::new(move |i: &Interval<T>, _: &Context<T>| -> Option<Interval<T>> {
+                name: "shift_by",
+                params: vec![],
+                offset: Arc::new(move |i: &Interval<T>, _: &Context<T>| -> Option<Interval<T>> {
                     Some(i.interval_round_to(next_grain) + &period)
                 }),
             };
@@ -1720,7 +2407,7 @@ mod tests {
         fn offset(i: &Interval<Paris>, _: &Context<Paris>) -> Option<Interval<Paris>> {
             Some(*i + PeriodComp::days(32))
         }
-        let walker = DayOfMonth::new(12).translate_with(offset)
+        let walker = DayOfMonth::new(12).translate_with("days", &[32], offset)
                 .to_walker(&context.reference, &context);
 
         assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 14).and_hms(0, 0, 0)),
@@ -1745,8 +2432,8 @@ mod tests {
         fn offset(i: &Interval<Paris>, _: &Context<Paris>) -> Option<Interval<Paris>> {
             Some(*i - PeriodComp::days(32))
         }
-        let walker = DayOfMonth::new(12).translate_with(offset).to_walker(&context.reference,
-                                                                             &context);
+        let walker = DayOfMonth::new(12).translate_with("days", &[-32], offset)
+                .to_walker(&context.reference, &context);
 
         assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 11).and_hms(0, 0, 0)),
                                               Grain::Day)),
@@ -1769,8 +2456,8 @@ mod tests {
         fn offset(i: &Interval<Paris>, _: &Context<Paris>) -> Option<Interval<Paris>> {
             Some(*i + PeriodComp::days(100))
         }
-        let walker = DayOfMonth::new(12).translate_with(offset).to_walker(&context.reference,
-                                                                             &context);
+        let walker = DayOfMonth::new(12).translate_with("days", &[100], offset)
+                .to_walker(&context.reference, &context);
 
         assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 23).and_hms(0, 0, 0)),
                                               Grain::Day)),
@@ -1825,4 +2512,155 @@ mod tests {
                    walker.backward.clone().skip(1).next());
 
     }
+
+    #[test]
+    fn test_description() {
+        let monday_3pm: RcConstraint<Paris> = DayOfWeek::new(Weekday::Mon).intersect(&Hour::clock_12(15));
+        assert_eq!("Intersect(DayOfWeek(Mon), Hour(15, 12h))", format!("{:?}", monday_3pm));
+        assert_eq!(DayOfWeek::new(Weekday::Mon).intersect(&Hour::clock_12(15)), monday_3pm);
+        assert!(DayOfWeek::new(Weekday::Mon).intersect(&Hour::clock_24(15)) != monday_3pm);
+
+        let shifted: RcConstraint<Paris> = DayOfMonth::new(12).shift_by(PeriodComp::days(2).into());
+        assert_eq!("ShiftBy(DayOfMonth(12), 2 Day)", format!("{:?}", shifted));
+        assert_eq!("TakeTheNth(1, Cycle(Week), not_immediate)",
+                   format!("{:?}", Cycle::rc::<Paris>(Grain::Week).take_the_nth_not_immediate(1)));
+
+        let days_after = |n: i64| {
+            DayOfMonth::new(12).translate_with("days_after", &[n], move |i: &Interval<Paris>, _: &Context<Paris>| {
+                Some(*i + PeriodComp::days(n))
+            })
+        };
+        assert_eq!("Translate(DayOfMonth(12), days_after(2))", format!("{:?}", days_after(2)));
+        assert_eq!(days_after(2), days_after(2));
+        assert!(days_after(2) != days_after(3));
+
+        let mut set = ::std::collections::HashSet::new();
+        set.insert(shifted.clone());
+        set.insert(DayOfMonth::new(12).shift_by(PeriodComp::days(2).into()));
+        set.insert(DayOfMonth::new(12).shift_by(PeriodComp::days(3).into()));
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn test_walk_within() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let march = Interval::starting_at(Moment(Paris.ymd(2017, 03, 01).and_hms(0, 0, 0)), Grain::Month);
+        let mondays = DayOfWeek::new(Weekday::Mon).walk_within(march, &context)
+            .into_iter()
+            .map(|interval| interval.start.day())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![6, 13, 20, 27], mondays);
+
+        let year = Interval::starting_at(Moment(Paris.ymd(2017, 01, 01).and_hms(0, 0, 0)), Grain::Year);
+        let months = Month::new(3).walk_within(year, &context).into_iter().collect::<Vec<_>>();
+        assert_eq!(vec![Interval::starting_at(Moment(Paris.ymd(2017, 03, 01).and_hms(0, 0, 0)), Grain::Month)], months);
+        assert_eq!(12, DayOfMonth::new(12).walk_within(year, &context).into_iter().count());
+    }
+
+    #[test]
+    fn test_week_start() {
+        let mut context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        context.week_start = Weekday::Sun;
+        let walker = Cycle::rc(Grain::Week).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)), Grain::Week)),
+                   walker.forward.clone().next());
+        context.week_start = Weekday::Sat;
+        let walker = Cycle::rc(Grain::Week).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 15).and_hms(0, 0, 0)), Grain::Week)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_day_part() {
+        let mut context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let evening = DayPart::new(PartOfDay::Evening, 18, 0);
+        let walker = evening.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval {
+                       start: Moment(Paris.ymd(2017, 04, 25).and_hms(18, 0, 0)),
+                       grain: Grain::Hour,
+                       end: Some(Moment(Paris.ymd(2017, 04, 26).and_hms(0, 0, 0))),
+                   }),
+                   walker.forward.clone().next());
+        context.part_of_day_hours.set(PartOfDay::Evening, 17, 21);
+        let walker = evening.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval {
+                       start: Moment(Paris.ymd(2017, 04, 25).and_hms(17, 0, 0)),
+                       grain: Grain::Hour,
+                       end: Some(Moment(Paris.ymd(2017, 04, 25).and_hms(21, 0, 0))),
+                   }),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_year_part() {
+        let mut context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let summer = YearPart::new(Season::Summer);
+        let walker = summer.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval {
+                       start: Moment(Paris.ymd(2017, 06, 21).and_hms(0, 0, 0)),
+                       grain: Grain::Day,
+                       end: Some(Moment(Paris.ymd(2017, 09, 24).and_hms(0, 0, 0))),
+                   }),
+                   walker.forward.clone().next());
+
+        context.hemisphere = Hemisphere::Southern;
+        context.season_calendar = SeasonCalendar::Meteorological;
+        let walker = summer.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval {
+                       start: Moment(Paris.ymd(2017, 12, 01).and_hms(0, 0, 0)),
+                       grain: Grain::Month,
+                       end: Some(Moment(Paris.ymd(2018, 03, 01).and_hms(0, 0, 0))),
+                   }),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_context_window() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        assert_eq!(Moment(Paris.ymd(-262_000, 1, 1).and_hms(0, 0, 0)), context.min.start);
+        assert_eq!(Moment(Paris.ymd(262_000, 1, 1).and_hms(0, 0, 0)), context.max.start);
+
+        let march_1955 = Month::new(3).intersect(&Year::new(1955));
+        let walker = march_1955.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(1955, 3, 1).and_hms(0, 0, 0)), Grain::Month)),
+                   walker.backward.clone().next());
+
+        let context = context.with_window(30, 10);
+        assert_eq!(Moment(Paris.ymd(1987, 1, 1).and_hms(0, 0, 0)), context.min.start);
+        assert_eq!(Moment(Paris.ymd(2027, 1, 1).and_hms(0, 0, 0)), context.max.start);
+        let walker = march_1955.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_business_days() {
+        let mut context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let day = |d: u32| Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, d).and_hms(0, 0, 0)), Grain::Day));
+        let nth = |n: i64, context: &Context<Paris>| {
+            NthBusinessDay::new(n).to_walker(&context.reference, context).forward.clone().next()
+                .or_else(|| NthBusinessDay::new(n).to_walker(&context.reference, context).backward.clone().next())
+        };
+        let april_2017 = Month::new(4).intersect(&Year::new(2017));
+        let last_business_day = BusinessDay::rc().last_of(&april_2017);
+
+        assert_eq!(day(25), nth(0, &context));
+        assert_eq!(day(28), nth(3, &context));
+        assert_eq!(day(24), nth(-1, &context));
+        assert_eq!(day(21), nth(-2, &context));
+        assert_eq!(day(28), last_business_day.to_walker(&context.reference, &context).forward.clone().next());
+
+        context.business_calendar = BusinessCalendar::default()
+            .with_holidays(vec![NaiveDate::from_ymd(2017, 4, 28), NaiveDate::from_ymd(2017, 4, 26)]);
+        assert_eq!(day(27), nth(1, &context));
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 02).and_hms(0, 0, 0)), Grain::Day)),
+                   nth(3, &context));
+        assert_eq!(day(27), last_business_day.to_walker(&context.reference, &context).forward.clone().next());
+
+        context.business_calendar = BusinessCalendar::new(&[Weekday::Fri, Weekday::Sat], vec![]);
+        assert_eq!(day(30), nth(3, &context));
+
+        context.business_calendar = BusinessCalendar::new(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+                                                            Weekday::Fri, Weekday::Sat, Weekday::Sun], vec![]);
+        assert_eq!(None, nth(1, &context));
+    }
 }
@@ -4,8 +4,16 @@ extern crate derive_new;
 #[macro_use]
 extern crate enum_primitive;
 extern crate vec_map;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
 
 mod period;
+mod timezone;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod interval_constraints;
 pub mod bidirectional_walker;
 pub mod walker;
@@ -13,12 +21,14 @@ pub mod walker;
 use std::ops;
 use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
-use chrono::{Duration, Datelike, Timelike};
-pub use chrono::{Weekday, Local, TimeZone};
+use chrono::{Duration, Datelike, Timelike, NaiveDateTime};
+pub use chrono::{Weekday, Local, TimeZone, FixedOffset, NaiveDate};
 use chrono::datetime::DateTime;
 pub use interval_constraints::*;
 pub use period::*;
+pub use timezone::*;
 
 
 #[derive(Clone)]
@@ -75,11 +85,7 @@ impl<T: TimeZone> ops::Deref for Moment<T> {
     }
 }
 
-impl Moment<Local> {
-    pub fn now() -> Moment<Local> {
-        Moment(Local::now())
-    }
-
+impl<T: TimeZone> Moment<T> {
     pub fn year(&self) -> i32 {
         self.0.year()
     }
@@ -92,11 +98,57 @@ impl Moment<Local> {
         self.0.day()
     }
 
+    pub fn weekday(&self) -> Weekday {
+        self.0.weekday()
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+
+    pub fn second(&self) -> u32 {
+        self.0.second()
+    }
+}
+
+impl Moment<Local> {
+    pub fn now() -> Moment<Local> {
+        Moment(Local::now())
+    }
+
     pub fn ymd(y: i32, m: u32, d: u32) -> Moment<Local> {
         Moment(Local.ymd(y, m, d).and_hms(0, 0, 0))
     }
 }
 
+impl Moment<Tz> {
+    pub fn now_in(tz: Tz) -> Moment<Tz> {
+        Moment(Local::now().with_timezone(&tz))
+    }
+}
+
+/// Parses an ISO 8601 date time like `2013-02-12T04:30:00+01:00`. Without UTC offset,
+/// the moment is taken in the local time zone.
+impl FromStr for Moment<Tz> {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Moment<Tz>, String> {
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(s) {
+            let tz = Tz::Fixed(*datetime.offset());
+            return Ok(Moment(datetime.with_timezone(&tz)));
+        }
+        let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+            .map_err(|e| format!("Invalid moment {:?}: {}", s, e))?;
+        Tz::Local.from_local_datetime(&naive)
+            .single()
+            .map(Moment)
+            .ok_or_else(|| format!("Invalid or ambiguous local moment {:?}", s))
+    }
+}
+
 impl<T: TimeZone> Moment<T> where <T as TimeZone>::Offset: Copy {
 
     fn add_months(self, n: i32) -> Moment<T> {
@@ -244,6 +296,16 @@ impl<T: TimeZone> Interval<T> where <T as TimeZone>::Offset: Copy {
         }
     }
 
+    fn start_round_to_week(self, week_start: Weekday) -> Interval<T> {
+        let day_offset = (7 + self.start.weekday().num_days_from_monday()
+                          - week_start.num_days_from_monday()) % 7;
+        Interval {
+            start: self.start.round_to(Grain::Day) - PeriodComp::days(day_offset as i64),
+            grain: Grain::Week,
+            end: None,
+        }
+    }
+
     fn interval_round_to(self, g: Grain) -> Interval<T> {
         Interval {
             start: self.start.round_to(g),
@@ -384,6 +446,7 @@ impl<T: TimeZone> ops::Sub<Period> for Interval<T> where <T as TimeZone>::Offset
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
     use chrono::{TimeZone, FixedOffset, NaiveDate, NaiveDateTime, LocalResult};
 
     #[derive(Copy, Clone, PartialEq, Eq)]
@@ -594,6 +657,17 @@ mod tests {
         assert_eq!(None, interval.intersect(other));
     }
 
+    #[test]
+    fn moment_from_str() {
+        assert_eq!(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)),
+                   Moment::<Tz>::from_str("2017-04-25T09:10:11+02:00").unwrap());
+        assert_eq!(Tz::east(2 * 3600),
+                   Moment::<Tz>::from_str("2017-04-25T09:10:11+02:00").unwrap().timezone());
+        assert_eq!(Moment(Local.ymd(2017, 04, 25).and_hms(9, 10, 11)),
+                   Moment::<Tz>::from_str("2017-04-25T09:10:11").unwrap());
+        assert!(Moment::<Tz>::from_str("tomorrow").is_err());
+    }
+
     #[test]
     fn seconds() {
         let interval = Interval {
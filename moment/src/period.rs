@@ -3,6 +3,7 @@ use vec_map::VecMap;
 
 enum_from_primitive! {
     #[derive(Debug,PartialEq,Copy,Clone,Eq,Ord,PartialOrd, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum Grain {
         Year = 0,
         Quarter = 1,
@@ -192,6 +193,7 @@ impl<'a> ops::Neg for &'a Period {
 
 
 #[derive(Debug, PartialEq, Copy, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PeriodComp {
     pub grain: Grain,
     pub quantity: i64,
@@ -0,0 +1,37 @@
+use std::fmt;
+
+use chrono::{DateTime, TimeZone};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use {Moment, Period, PeriodComp, Tz};
+
+/// Moments are serialized as RFC 3339 (ISO 8601) strings, with their UTC offset.
+impl<T: TimeZone> Serialize for Moment<T> where T::Offset: fmt::Display {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(&self.0.to_rfc3339())
+    }
+}
+
+/// The deserialized moment lives in the fixed offset time zone found in the string.
+impl<'de> Deserialize<'de> for Moment<Tz> {
+    fn deserialize<D>(deserializer: D) -> Result<Moment<Tz>, D::Error> where D: Deserializer<'de> {
+        let value = String::deserialize(deserializer)?;
+        let datetime = DateTime::parse_from_rfc3339(&value).map_err(de::Error::custom)?;
+        let tz = Tz::Fixed(*datetime.offset());
+        Ok(Moment(datetime.with_timezone(&tz)))
+    }
+}
+
+/// Periods are serialized as the list of their components.
+impl Serialize for Period {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        self.comps().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Period {
+    fn deserialize<D>(deserializer: D) -> Result<Period, D::Error> where D: Deserializer<'de> {
+        let comps: Vec<PeriodComp> = Vec::deserialize(deserializer)?;
+        Ok(comps.into_iter().fold(Period::default(), |period, comp| period + comp))
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+
+use chrono::{FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+
+/// Time zone used to resolve constraints: either the local time zone of the process
+/// or a fixed offset supplied by the caller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tz {
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl Tz {
+    /// Fixed offset time zone, `seconds` east of UTC.
+    pub fn east(seconds: i32) -> Tz {
+        Tz::Fixed(FixedOffset::east(seconds))
+    }
+
+    /// Fixed offset time zone, `seconds` west of UTC.
+    pub fn west(seconds: i32) -> Tz {
+        Tz::Fixed(FixedOffset::west(seconds))
+    }
+
+    fn with_offset(&self, offset: FixedOffset) -> TzOffset {
+        TzOffset { tz: *self, offset }
+    }
+}
+
+/// Offset of a `Tz` at a given instant. It keeps track of the zone it comes from so that
+/// moments computed from it stay in the same zone (daylight saving included).
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct TzOffset {
+    tz: Tz,
+    offset: FixedOffset,
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.offset)
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.offset)
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.tz
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        match self {
+            &Tz::Local => Local.offset_from_local_date(local).map(|o| self.with_offset(o)),
+            &Tz::Fixed(fixed) => fixed.offset_from_local_date(local).map(|o| self.with_offset(o)),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        match self {
+            &Tz::Local => Local.offset_from_local_datetime(local).map(|o| self.with_offset(o)),
+            &Tz::Fixed(fixed) => fixed.offset_from_local_datetime(local).map(|o| self.with_offset(o)),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        match self {
+            &Tz::Local => self.with_offset(Local.offset_from_utc_date(utc)),
+            &Tz::Fixed(fixed) => self.with_offset(fixed.offset_from_utc_date(utc)),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        match self {
+            &Tz::Local => self.with_offset(Local.offset_from_utc_datetime(utc)),
+            &Tz::Fixed(fixed) => self.with_offset(fixed.offset_from_utc_datetime(utc)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+    use {Moment, Grain, PeriodComp};
+
+    #[test]
+    fn test_fixed_tz_keeps_zone() {
+        let tz = Tz::east(9 * 3600);
+        let moment = Moment(tz.ymd(2017, 04, 25).and_hms(23, 10, 11));
+        let next_day = moment + PeriodComp::days(1);
+        assert_eq!(tz, next_day.timezone());
+        assert_eq!(23, next_day.hour());
+        assert_eq!(Moment(tz.ymd(2017, 04, 26).and_hms(0, 0, 0)), next_day.round_to(Grain::Day));
+    }
+
+    #[test]
+    fn test_same_instant_in_different_tz() {
+        let paris = Moment(Tz::east(2 * 3600).ymd(2017, 04, 25).and_hms(9, 0, 0));
+        let new_york = Moment(Tz::west(4 * 3600).ymd(2017, 04, 25).and_hms(3, 0, 0));
+        assert_eq!(paris, new_york);
+    }
+}
@@ -40,6 +40,13 @@ pub enum Walker<V: Copy + Clone> {
         lhs: Box<Walker<V>>,
         rhs: Box<Walker<V>>,
     },
+    Merge {
+        lhs: Box<Walker<V>>,
+        rhs: Box<Walker<V>>,
+        lhs_next: Option<V>,
+        rhs_next: Option<V>,
+        before: Rc<Fn(&V, &V) -> bool>,
+    },
 }
 
 impl<V: Copy + Clone> Walker<V> {
@@ -135,6 +142,19 @@ impl<V: Copy + Clone> Walker<V> {
         }
     }
 
+    /// Interleaves two ordered walkers, `before(a, b)` telling whether `a` comes first.
+    pub fn merge<F>(&self, other: &Walker<V>, before: F) -> Walker<V>
+        where F: Fn(&V, &V) -> bool + 'static
+    {
+        Walker::Merge {
+            lhs: Box::new(self.clone()),
+            rhs: Box::new(other.clone()),
+            lhs_next: None,
+            rhs_next: None,
+            before: Rc::new(before),
+        }
+    }
+
     pub fn next(&mut self) -> Option<V> {
         match self {
             &mut Walker::Vec(ref mut vec) => vec.pop(),
@@ -254,6 +274,29 @@ impl<V: Copy + Clone> Walker<V> {
                      ref mut lhs,
                      ref mut rhs,
                  } => lhs.next().or_else(|| rhs.next()),
+            &mut Walker::Merge {
+                     ref mut lhs,
+                     ref mut rhs,
+                     ref mut lhs_next,
+                     ref mut rhs_next,
+                     ref before,
+                 } => {
+                if lhs_next.is_none() {
+                    *lhs_next = lhs.next();
+                }
+                if rhs_next.is_none() {
+                    *rhs_next = rhs.next();
+                }
+                let take_lhs = match (lhs_next.as_ref(), rhs_next.as_ref()) {
+                    (Some(l), Some(r)) => before(l, r),
+                    (l, _) => l.is_some(),
+                };
+                if take_lhs {
+                    lhs_next.take()
+                } else {
+                    rhs_next.take()
+                }
+            }
         }
     }
 }
@@ -361,4 +404,12 @@ mod tests {
         assert_eq!(vec![1], c(w!().chain(&w!(1))));
         assert_eq!(vec![1, 2], c(w!(1).chain(&w!(2))));
     }
+
+    #[test]
+    fn test_merge() {
+        let before = |a: &usize, b: &usize| a <= b;
+        assert_eq!(Vec::<usize>::new(), c(w!().merge(&w!(), before)));
+        assert_eq!(vec![1, 2], c(w!(1, 2).merge(&w!(), before)));
+        assert_eq!(vec![1, 2, 3, 4, 5], c(w!(1, 4, 5).merge(&w!(2, 3), before)));
+    }
 }
@@ -0,0 +1,170 @@
+#[macro_use]
+extern crate clap;
+extern crate rustling_ontology;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+extern crate tiny_http;
+
+use std::io::Read;
+use std::str::FromStr;
+
+use rustling_ontology::*;
+use rustling_ontology::output::OutputMatch;
+use tiny_http::{Header, Method, Response, Server};
+
+#[derive(Debug, Deserialize)]
+struct ParseRequest {
+    text: String,
+    lang: String,
+    reference: Option<Moment<Tz>>,
+    kinds: Option<Vec<OutputKind>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    max_body_size: usize,
+    max_text_length: usize,
+}
+
+struct Service {
+    parsers: Vec<(Lang, Parser)>,
+    limits: Limits,
+}
+
+fn error_body(message: &str) -> String {
+    json!({ "error": message }).to_string()
+}
+
+impl Service {
+    fn handle(&self, method: &Method, url: &str, body: &mut Read) -> (u16, String) {
+        let path = url.split('?').next().unwrap_or("");
+        match (method, path) {
+            (&Method::Get, "/health") => {
+                let langs = self.parsers.iter().map(|&(lang, _)| lang.to_string()).collect::<Vec<_>>();
+                (200, json!({ "status": "ok", "langs": langs }).to_string())
+            }
+            (&Method::Post, "/parse") => self.parse(body),
+            (_, "/health") | (_, "/parse") => (405, error_body("Method not allowed")),
+            _ => (404, error_body("Not found")),
+        }
+    }
+
+    fn parse(&self, body: &mut Read) -> (u16, String) {
+        let mut content = String::new();
+        if body.take(self.limits.max_body_size as u64 + 1).read_to_string(&mut content).is_err() {
+            return (400, error_body("Body is not valid UTF-8"));
+        }
+        if content.len() > self.limits.max_body_size {
+            return (413, error_body("Body is too large"));
+        }
+        let request: ParseRequest = match serde_json::from_str(&content) {
+            Ok(request) => request,
+            Err(e) => return (400, error_body(&format!("Invalid request: {}", e))),
+        };
+        if request.text.chars().count() > self.limits.max_text_length {
+            return (413, error_body("Text is too long"));
+        }
+        let lang = match Lang::from_str(&request.lang) {
+            Ok(lang) => lang,
+            Err(e) => return (400, error_body(&e)),
+        };
+        let parser = match self.parsers.iter().find(|&&(l, _)| l == lang) {
+            Some(&(_, ref parser)) => parser,
+            None => return (400, error_body(&format!("Language {} is not loaded", request.lang))),
+        };
+        let context = request.reference
+            .map(|reference| ResolverContext::new(Interval::starting_at(reference, Grain::Second)))
+            .unwrap_or_default();
+        let kinds = request.kinds.unwrap_or_else(|| OutputKind::all());
+        let sentence = request.text.to_lowercase();
+        match parser.parse_with_kind_order(&*sentence, &context, &kinds) {
+            Ok(entities) => {
+                let matches = entities.into_iter().map(OutputMatch::from).collect::<Vec<_>>();
+                (200, serde_json::to_string(&matches).unwrap())
+            }
+            Err(e) => (500, error_body(&format!("{}", e))),
+        }
+    }
+}
+
+fn main() {
+    let matches = clap_app!(rustling_server =>
+        (@arg langs: -l --langs +takes_value +use_delimiter default_value[en] "2-letter language codes to load, coma separated (default to \"en\")")
+        (@arg address: -a --address default_value[127.0.0.1:8080] "Address to listen on (default to 127.0.0.1:8080)")
+        (@arg max_body_size: --("max-body-size") default_value[16384] "Maximum size of a request body, in bytes")
+        (@arg max_text_length: --("max-text-length") default_value[1000] "Maximum length of a text to parse, in chars")
+    ).get_matches();
+
+    let langs = values_t!(matches.values_of("langs"), Lang).unwrap_or_else(|e| e.exit());
+    let limits = Limits {
+        max_body_size: value_t!(matches.value_of("max_body_size"), usize).unwrap_or_else(|e| e.exit()),
+        max_text_length: value_t!(matches.value_of("max_text_length"), usize).unwrap_or_else(|e| e.exit()),
+    };
+    let service = Service {
+        parsers: langs.into_iter().map(|lang| (lang, build_parser(lang).unwrap())).collect(),
+        limits: limits,
+    };
+
+    let address = matches.value_of("address").unwrap();
+    let server = Server::http(address).unwrap();
+    let content_type = Header::from_str("Content-Type: application/json").unwrap();
+    println!("Listening on {}", address);
+
+    for mut request in server.incoming_requests() {
+        let method = request.method().clone();
+        let url = request.url().to_string();
+        let (status, body) = service.handle(&method, &url, request.as_reader());
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            println!("Failed to respond to {}: {}", url, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> Service {
+        Service {
+            parsers: vec![(Lang::EN, build_parser(Lang::EN).unwrap())],
+            limits: Limits { max_body_size: 256, max_text_length: 32 },
+        }
+    }
+
+    #[test]
+    fn test_health() {
+        let (status, body) = service().handle(&Method::Get, "/health", &mut "".as_bytes());
+        assert_eq!(200, status);
+        assert_eq!(json!({ "status": "ok", "langs": ["EN"] }), serde_json::from_str::<serde_json::Value>(&body).unwrap());
+    }
+
+    #[test]
+    fn test_parse() {
+        let service = service();
+        let request = r#"{"text": "tomorrow", "lang": "en", "reference": "2013-02-12T04:30:00+01:00", "kinds": ["Time"]}"#;
+        let (status, body) = service.handle(&Method::Post, "/parse", &mut request.as_bytes());
+        assert_eq!(200, status);
+        let matches: Vec<OutputMatch> = serde_json::from_str(&body).unwrap();
+        assert_eq!(1, matches.len());
+        assert_eq!(OutputKind::Time, matches[0].kind);
+    }
+
+    #[test]
+    fn test_limits() {
+        let service = service();
+        let request = r#"{"text": "in the morning of the first day of the next month", "lang": "en"}"#;
+        assert_eq!(413, service.handle(&Method::Post, "/parse", &mut request.as_bytes()).0);
+        let request = format!(r#"{{"text": "{}", "lang": "en"}}"#, "a".repeat(300));
+        assert_eq!(413, service.handle(&Method::Post, "/parse", &mut request.as_bytes()).0);
+        let request = r#"{"text": "tomorrow", "lang": "fr"}"#;
+        assert_eq!(400, service.handle(&Method::Post, "/parse", &mut request.as_bytes()).0);
+        assert_eq!(405, service.handle(&Method::Get, "/parse", &mut "".as_bytes()).0);
+        assert_eq!(404, service.handle(&Method::Get, "/", &mut "".as_bytes()).0);
+    }
+}
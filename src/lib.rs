@@ -29,14 +29,22 @@ extern crate rustling_ontology_grammar as grammar;
 extern crate rustling_ontology_values;
 
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, Range, Value, Sym, ParsingAnalysis};
+use rustling::Candidate;
 pub use rustling::errors::*;
 pub use grammar::{Lang, dims};
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
-pub use rustling_ontology_values::{ResolverContext, IdentityContext, ParsingContext};
+pub use rustling_ontology_values::{ResolverContext, ResolutionPolicy, IdentityContext, ParsingContext};
 pub use rustling_ontology_moment::Interval;
+pub use rustling_ontology_moment::{Moment, Tz};
 pub use rustling_ontology_moment::Grain;
+pub use rustling_ontology_moment::{DateOrder, Hemisphere, NaiveDate, PartOfDay, SeasonCalendar, Weekday};
+
+use std::cmp::Ordering;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
 
 mod parser;
 mod tagger;
@@ -89,6 +97,79 @@ impl Parser {
         self.parse_with_kind_order(input, context, &all_output)
     }
 
+    /// Returns, for each span matched by `parse_with_kind_order`, at most `n` ranked
+    /// interpretations. The first one is the match `parse_with_kind_order` returns. The others
+    /// take in turn from each interpretation within the span, with other kinds or rule trees
+    /// (e.g. the number in "at 3"), the next of its resolutions (e.g. the previous and next
+    /// occurrences of a time).
+    pub fn parse_n_best_with_kind_order(&self,
+                                        input: &str,
+                                        context: &ResolverContext,
+                                        order: &[OutputKind],
+                                        n: usize)
+                                        -> RustlingResult<Vec<Vec<ParserMatch<Output>>>> {
+        let tagger = CandidateTagger {
+            order: order,
+            context: context,
+            resolve_all_candidates: false,
+        };
+        let dims = order.iter().map(|o| o.to_dim()).collect::<Vec<_>>();
+        let priority = |candidate: &Candidate<dimension::Dimension, Option<Output>>| {
+            dims.iter().position(|k| *k == candidate.node.value.kind()).unwrap_or(dims.len())
+        };
+        let mut candidates = self.0.candidates(input, &tagger)?;
+        candidates.sort_by(|a, b| {
+            b.tagged.cmp(&a.tagged)
+                .then_with(|| priority(a).cmp(&priority(b)))
+                .then_with(|| b.match_.probalog.partial_cmp(&a.match_.probalog).unwrap_or(Ordering::Equal))
+        });
+
+        let mut spans = candidates.iter()
+            .filter(|c| c.tagged)
+            .map(|c| c.match_.byte_range)
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|r| r.0);
+
+        Ok(spans.into_iter()
+            .map(|span| {
+                let resolutions = candidates.iter()
+                    .filter(|c| c.match_.byte_range.0 >= span.0 && c.match_.byte_range.1 <= span.1)
+                    .map(|c| (c, context.resolve_n(&c.node.value, n)))
+                    .collect::<Vec<_>>();
+                let mut alternatives: Vec<ParserMatch<Output>> = vec![];
+                // Round-robin by rank, so that every interpretation gets a slot before the
+                // further resolutions of the first ones
+                for rank in 0..n {
+                    for &(c, ref values) in resolutions.iter() {
+                        if let Some(value) = values.get(rank) {
+                            if alternatives.len() < n && alternatives.iter().all(|a| a.value != *value) {
+                                alternatives.push(ParserMatch {
+                                    byte_range: c.match_.byte_range,
+                                    char_range: c.match_.char_range,
+                                    parsing_tree_height: c.match_.parsing_tree_height,
+                                    parsing_tree_num_nodes: c.match_.parsing_tree_num_nodes,
+                                    value: value.clone(),
+                                    probalog: c.match_.probalog,
+                                    latent: c.match_.latent,
+                                });
+                            }
+                        }
+                    }
+                }
+                alternatives
+            })
+            .collect())
+    }
+
+    pub fn parse_n_best(&self,
+                        input: &str,
+                        context: &ResolverContext,
+                        n: usize)
+                        -> RustlingResult<Vec<Vec<ParserMatch<Output>>>> {
+        let all_output = OutputKind::all();
+        self.parse_n_best_with_kind_order(input, context, &all_output, n)
+    }
+
     pub fn analyse_with_kind_order(&self,
                                     examples: Vec<&str>,
                                     context: &ResolverContext,
@@ -120,32 +201,72 @@ pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
     build_raw_parser(lang).map(::Parser)
 }
 
+/// Obtain a parser for a given language, using a model saved by `train_parser_and_save_model`
+/// instead of the one embedded in the crate.
+pub fn build_parser_from_model<R: Read>(lang: Lang, model: R) -> RustlingResult<Parser> {
+    build_raw_parser_from_model(lang, model).map(::Parser)
+}
 
+/// Obtain a parser for a given language, reading its model from a file.
+pub fn build_parser_from_model_file<P: AsRef<Path>>(lang: Lang, path: P) -> RustlingResult<Parser> {
+    let file = fs::File::open(path.as_ref())
+        .map_err(|e| format!("Could not open model {:?}: {}", path.as_ref(), e))?;
+    build_parser_from_model(lang, io::BufReader::new(file))
+}
 
 /// Obtain a parser for a given language.
 pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
+    let model: &[u8] = match lang {
+        #[cfg(feature="lang-de")]
+        Lang::DE => include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp")),
+        #[cfg(feature="lang-en")]
+        Lang::EN => include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp")),
+        #[cfg(feature="lang-es")]
+        Lang::ES => include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp")),
+        #[cfg(feature="lang-fr")]
+        Lang::FR => include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp")),
+        #[cfg(feature="lang-ja")]
+        Lang::JA => include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp")),
+        #[cfg(feature="lang-ko")]
+        Lang::KO => include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp")),
+        #[cfg(feature="lang-zh")]
+        Lang::ZH => include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp")),
+        // The grammar crate may have more languages than this crate, when another crate of the
+        // build enables them
+        #[allow(unreachable_patterns)]
+        _ => return Err(format!("language {:?} not compiled in", lang).into()),
+    };
+    build_raw_parser_from_model(lang, model)
+}
+
+/// Obtain a parser for a given language from a serialized model.
+pub fn build_raw_parser_from_model<R: Read>(lang: Lang, model: R) -> RustlingResult<RawParser> {
     let rules = grammar::rules(lang)?;
-    let model = match lang {
-        Lang::DE => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..]) },
-        Lang::EN => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..]) },
-        Lang::ES => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp"))[..]) },
-        Lang::FR => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp"))[..]) },
-        Lang::JA => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp"))[..]) },
-        Lang::KO => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp"))[..]) },
-        Lang::ZH => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp"))[..]) },
-    }.map_err(|e| format!("{:?}", e))?;
+    let model = ::rmp_serde::decode::from_read(model).map_err(|e| format!("{:?}", e))?;
     Ok(::RawParser::new(rules, model, ::parser::FeatureExtractor()))
 }
 
-
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
+    train(lang, None)
+}
+
+/// Train a parser for a given language and write its model, so that it can be loaded later
+/// with `build_parser_from_model`.
+pub fn train_parser_and_save_model<W: Write>(lang: Lang, writer: &mut W) -> RustlingResult<Parser> {
+    train(lang, Some(writer as &mut Write))
+}
+
+fn train(lang: Lang, writer: Option<&mut Write>) -> RustlingResult<Parser> {
     let rules = grammar::rules(lang)?;
     let examples = grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor())?;
+    if let Some(writer) = writer {
+        ::rmp_serde::encode::write(writer, &model).map_err(|e| format!("{:?}", e))?;
+    }
     Ok(Parser(::rustling::Parser::new(rules, model, ::parser::FeatureExtractor())))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature="lang-en"))]
 mod tests {
     use super::*;
 
@@ -159,6 +280,274 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    fn test_time_in_fixed_timezone() {
+        use rustling_ontology_moment::TimeZone;
+        let tz = Tz::west(5 * 3600);
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(tz.ymd(2013, 2, 12).and_hms(22, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_with_kind_order("tomorrow at 9am", &ctx, &[OutputKind::Time]).unwrap();
+        let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(tz.ymd(2013, 2, 13).and_hms(9, 0, 0)), time.moment);
+        assert_eq!(tz, time.moment.timezone());
+    }
+
+    #[test]
+    fn test_parser_from_model() {
+        let model: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"));
+        let parser = build_parser_from_model(Lang::EN, model).unwrap();
+        let result = parser.parse_with_kind_order("twenty-one", &ResolverContext::default(), &[OutputKind::Number]).unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(21, int.0);
+        assert!(build_parser_from_model(Lang::EN, &b"not a model"[..]).is_err());
+    }
+
+    #[test]
+    fn test_parser_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Parser>();
+        assert_send_sync::<dimension::Dimension>();
+
+        let parser = ::std::sync::Arc::new(build_parser(Lang::EN).unwrap());
+        let handles = (0..2).map(|_| {
+            let parser = parser.clone();
+            ::std::thread::spawn(move || {
+                parser.parse_with_kind_order("tomorrow", &ResolverContext::default(), &[OutputKind::Time]).unwrap().len()
+            })
+        }).collect::<Vec<_>>();
+        for handle in handles {
+            assert_eq!(1, handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parse_n_best() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_n_best_with_kind_order("monday", &ctx, &[OutputKind::Time], 3).unwrap();
+        assert_eq!(1, result.len());
+        let days = result[0].iter()
+            .map(|m| { let time: output::TimeOutput = m.value.clone().attempt_into().unwrap(); time.moment.day() })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![18, 11, 25], days);
+
+        let best = parser.parse_with_kind_order("monday", &ctx, &[OutputKind::Time]).unwrap();
+        assert_eq!(best[0].value, result[0][0].value);
+
+        let result = parser.parse_n_best_with_kind_order("at 3", &ctx, &[OutputKind::Time, OutputKind::Number], 3).unwrap();
+        let span = result.iter().find(|alternatives| alternatives[0].byte_range == Range(0, 4)).unwrap();
+        assert_eq!(OutputKind::Time, span[0].value.kind());
+        assert!(span.iter().any(|m| m.value.kind() == OutputKind::Number));
+    }
+
+    #[test]
+    fn test_resolution_policy() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let resolve = |sentence: &str, policy: ResolutionPolicy| {
+            let result = parser.parse_with_kind_order(sentence, &ctx.clone().with_policy(policy), &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            (time.moment.year(), time.moment.month(), time.moment.day())
+        };
+        assert_eq!((2013, 2, 18), resolve("monday", ResolutionPolicy::PreferFuture));
+        assert_eq!((2013, 2, 11), resolve("monday", ResolutionPolicy::PreferPast));
+        assert_eq!((2013, 2, 11), resolve("monday", ResolutionPolicy::Nearest));
+        assert_eq!((2013, 2, 5), resolve("tuesday", ResolutionPolicy::PreferPast));
+        assert_eq!((2012, 3, 1), resolve("march", ResolutionPolicy::PreferPast));
+        assert_eq!((2013, 3, 1), resolve("march", ResolutionPolicy::Nearest));
+        assert_eq!((2013, 2, 12), resolve("today", ResolutionPolicy::PreferPast));
+        assert_eq!((2013, 2, 13), resolve("tomorrow", ResolutionPolicy::PreferPast));
+    }
+
+    #[test]
+    fn test_week_start_and_part_of_day_hours() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second))
+            .with_week_start(Weekday::Sun)
+            .with_part_of_day_hours(PartOfDay::Evening, 17, 21);
+        let parser = build_parser(Lang::EN).unwrap();
+
+        let result = parser.parse_with_kind_order("this week", &ctx, &[OutputKind::Time]).unwrap();
+        let week: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Tz::Local.ymd(2013, 2, 10).and_hms(0, 0, 0)), week.moment);
+
+        let result = parser.parse_with_kind_order("this evening", &ctx, &[OutputKind::Time]).unwrap();
+        match result[0].value {
+            Output::TimeInterval(output::TimeIntervalOutput::Between { start, end, .. }) => {
+                assert_eq!(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(17, 0, 0)), start);
+                assert_eq!(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(21, 0, 0)), end);
+            }
+            ref output => panic!("Unexpected output {:?}", output),
+        }
+    }
+
+    #[test]
+    fn test_date_order() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let dates = |ctx: &ResolverContext| {
+            parser.parse_n_best_with_kind_order("3/4/2015", ctx, &[OutputKind::Time], 2).unwrap()[0]
+                .iter()
+                .map(|m| { let time: output::TimeOutput = m.value.clone().attempt_into().unwrap(); (time.moment.month(), time.moment.day()) })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![(3, 4)], dates(&ctx));
+        assert_eq!(vec![(4, 3)], dates(&ctx.clone().with_date_order(DateOrder::DayFirst)));
+        assert_eq!(vec![(3, 4), (4, 3)], dates(&ctx.with_date_order(DateOrder::Both)));
+    }
+
+    #[test]
+    fn test_date_order_is_respected() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let times = |sentence: &str, ctx: &ResolverContext| {
+            parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap()
+                .into_iter()
+                .filter(|m| m.byte_range == Range(0, sentence.len()))
+                .count()
+        };
+        let ctx = ResolverContext::default();
+        assert_eq!(0, times("24/7", &ctx));
+        assert_eq!(0, times("31/10/1974", &ctx));
+        assert_eq!(0, times("31/10/1974", &ctx.clone().with_date_order(DateOrder::MonthFirst)));
+        assert_eq!(1, times("31/10/1974", &ctx.with_date_order(DateOrder::DayFirst)));
+    }
+
+    #[test]
+    fn test_search_window() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let year = |sentence: &str, ctx: &ResolverContext| {
+            parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap()
+                .into_iter()
+                .next()
+                .map(|m| { let time: output::TimeOutput = m.value.attempt_into().unwrap(); time.moment.year() })
+        };
+        assert_eq!(Some(2040), year("march 2040", &ctx));
+        assert_eq!(Some(1955), year("march 1955", &ctx));
+        assert_eq!(Some(2045), year("march 2045", &ctx.clone().with_search_window(30, 50)));
+        assert!(year("march 1955", &ctx.with_search_window(30, 30)) != Some(1955));
+    }
+
+    #[test]
+    fn test_business_days() {
+        use rustling_ontology_moment::TimeZone;
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Tz::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser(Lang::EN).unwrap();
+        let day = |sentence: &str, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            (time.moment.month(), time.moment.day())
+        };
+        assert_eq!((2, 15), day("in 3 business days", &ctx));
+        let with_holiday = ctx.clone().with_holidays(vec![NaiveDate::from_ymd(2013, 2, 14)]);
+        assert_eq!((2, 18), day("in 3 business days", &with_holiday));
+        let with_weekend = ctx.clone().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+        assert_eq!((2, 17), day("in 3 business days", &with_weekend));
+        assert_eq!((2, 27), day("the last business day of the month", &with_holiday.with_holidays(vec![NaiveDate::from_ymd(2013, 2, 28)])));
+    }
+
+    #[test]
+    fn test_quantity() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_with_kind_order("add 2 cups and a half of flour", &ResolverContext::default(), &[OutputKind::Quantity]).unwrap();
+        let quantity: output::QuantityOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(2.5, quantity.value);
+        assert_eq!(Some("cup"), quantity.unit);
+        assert_eq!(dimension::QuantityFamily::Volume, quantity.family);
+        assert_eq!(Some("flour".to_string()), quantity.product);
+    }
+
+    #[test]
+    fn test_kelvin_needs_a_unit() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse("5k race", &ResolverContext::default()).unwrap();
+        assert!(result.iter().all(|m| m.value.kind() != OutputKind::Temperature));
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(5000, int.0);
+
+        let result = parser.parse("300 kelvin", &ResolverContext::default()).unwrap();
+        let temperature: output::TemperatureOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some("kelvin"), temperature.unit);
+    }
+
+    #[test]
+    fn test_pound_is_a_mass() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let order = [OutputKind::Quantity, OutputKind::AmountOfMoney];
+        let result = parser.parse_with_kind_order("2 pounds", &ResolverContext::default(), &order).unwrap();
+        let quantity: output::QuantityOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((2.0, Some("pound")), (quantity.value, quantity.unit));
+    }
+
+    #[test]
+    fn test_bare_product_needs_a_known_word() {
+        let parser = build_parser(Lang::DE).unwrap();
+        let products = |sentence: &str| {
+            parser.parse_with_kind_order(sentence, &ResolverContext::default(), &[OutputKind::Quantity]).unwrap()
+                .into_iter()
+                .map(|m| { let quantity: output::QuantityOutput = m.value.attempt_into().unwrap(); quantity.product })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![Some("äpfel".to_string())], products("ein kilo äpfel"));
+        assert_eq!(vec![None], products("2 kilo für 3 euro"));
+        assert_eq!(vec![None], products("1 liter und"));
+    }
+
+    #[test]
+    fn test_pound_is_money() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_with_kind_order("20 pounds", &ResolverContext::default(), &[OutputKind::AmountOfMoney]).unwrap();
+        let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((20.0, Some("£")), (money.value, money.unit));
+    }
+
+    #[test]
+    fn test_money_bound_inclusivity() {
+        use output::AmountOfMoneyIntervalOutput::*;
+        let parser = build_parser(Lang::EN).unwrap();
+        let interval = |sentence: &str| {
+            let result = parser.parse(sentence, &ResolverContext::default()).unwrap();
+            let interval: output::AmountOfMoneyIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+            interval
+        };
+        let five_dollars = output::AmountOfMoneyOutput { value: 5.0, precision: dimension::Precision::Exact, unit: Some("$") };
+        assert_eq!(After(five_dollars), interval("more than 5 dollars"));
+        assert_eq!(AtLeast(five_dollars), interval("at least 5 dollars"));
+        assert_eq!(Before(five_dollars), interval("less than $5"));
+        assert_eq!(AtMost(five_dollars), interval("at most $5"));
+    }
+
+    #[test]
+    fn test_temperature_bound_inclusivity() {
+        use output::TemperatureIntervalOutput::*;
+        let parser = build_parser(Lang::EN).unwrap();
+        let interval = |sentence: &str| {
+            let result = parser.parse(sentence, &ResolverContext::default()).unwrap();
+            let interval: output::TemperatureIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+            interval
+        };
+        let twenty_degrees = output::TemperatureOutput { value: 20.0, unit: Some("degree"), latent: false, delta: false };
+        assert_eq!(After(twenty_degrees), interval("above 20 degrees"));
+        assert_eq!(AtLeast(twenty_degrees), interval("at least 20 degrees"));
+        assert_eq!(Before(twenty_degrees), interval("below 20 degrees"));
+        assert_eq!(AtMost(twenty_degrees), interval("at most 20 degrees"));
+    }
+
+    #[test]
+    fn test_number_range_does_not_hide_time() {
+        let parser = build_parser(Lang::FR).unwrap();
+        let result = parser.parse("de 5 à 7", &ResolverContext::default()).unwrap();
+        assert_eq!(OutputKind::Time, result[0].value.kind());
+
+        let result = parser.parse_with_kind_order("de 5 à 7", &ResolverContext::default(), &[OutputKind::NumberRange]).unwrap();
+        let range: output::NumberRangeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((Some(5.0), Some(7.0)), (range.min, range.max));
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
@@ -1,5 +1,5 @@
 use rustling::{AttemptFrom, Check, ParsedNode};
-use moment::{Grain, Interval, Moment, Local, Period};
+use moment::{Grain, Interval, Moment, Tz, Period};
 use dimension::*;
 use output::*;
 use context::{ParsingContext, ResolverContext};
@@ -55,6 +55,32 @@ pub fn check_float(v: f32) -> CheckFloat {
     CheckFloat { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckNumberRange {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+}
+
+impl Check<Dimension> for CheckNumberRange {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        NumberRangeValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max
+                && v.min_inclusive == self.min_inclusive && v.max_inclusive == self.max_inclusive)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_number_range(min: Option<f32>, max: Option<f32>, min_inclusive: bool, max_inclusive: bool) -> CheckNumberRange {
+    CheckNumberRange {
+        min: min,
+        max: max,
+        min_inclusive: min_inclusive,
+        max_inclusive: max_inclusive,
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckDuration {
     pub period: Period,
@@ -78,7 +104,7 @@ pub fn check_duration(period: Period, precision: Precision) -> CheckDuration {
 pub struct CheckMoment {
     pub direction: Option<Direction>,
     pub precision: Precision,
-    pub interval: Interval<Local>,
+    pub interval: Interval<Tz>,
     pub context: ResolverContext,
 }
 
@@ -127,7 +153,7 @@ impl Check<Dimension> for CheckMoment {
     }
 }
 
-pub fn check_moment(context: ResolverContext, moment: Moment<Local>, grain: Grain, precision: Precision, direction: Option<Direction>)
+pub fn check_moment(context: ResolverContext, moment: Moment<Tz>, grain: Grain, precision: Precision, direction: Option<Direction>)
                     -> CheckMoment {
     CheckMoment {
         direction: direction,
@@ -139,7 +165,7 @@ pub fn check_moment(context: ResolverContext, moment: Moment<Local>, grain: Grai
 
 #[derive(Debug)]
 pub struct CheckMomentSpan {
-    pub interval: Interval<Local>,
+    pub interval: Interval<Tz>,
     pub precision: Precision,
     pub context: ResolverContext,
 }
@@ -159,7 +185,7 @@ impl Check<Dimension> for CheckMomentSpan {
     }
 }
 
-pub fn check_moment_span(context: ResolverContext, precision: Precision, start: Moment<Local>, end: Moment<Local>, grain: Grain)
+pub fn check_moment_span(context: ResolverContext, precision: Precision, start: Moment<Tz>, end: Moment<Tz>, grain: Grain)
                          -> CheckMomentSpan {
     CheckMomentSpan { interval: Interval::new(start, Some(end), grain), precision, context }
 }
@@ -187,16 +213,40 @@ pub fn check_finance(value: f32, unit: Option<&'static str>, precision: Precisio
     }
 }
 
+#[derive(Debug)]
+pub struct CheckFinanceInterval {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckFinanceInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AmountOfMoneyIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_finance_interval(min: Option<f32>, max: Option<f32>, unit: Option<&'static str>) -> CheckFinanceInterval {
+    CheckFinanceInterval {
+        min: min,
+        max: max,
+        unit: unit,
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckTemperature {
     pub value: f32,
     pub unit: Option<&'static str>,
+    pub delta: bool,
 }
 
 impl Check<Dimension> for CheckTemperature {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         TemperatureValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.unit == self.unit && v.delta == self.delta)
             .unwrap_or(false)
     }
 }
@@ -205,5 +255,122 @@ pub fn check_temperature(value: f32, unit: Option<&'static str>) -> CheckTempera
     CheckTemperature {
         value: value,
         unit: unit,
+        delta: false,
+    }
+}
+
+/// Checks a change of temperature, "two degrees warmer" being a delta of 2 degrees.
+pub fn check_temperature_delta(value: f32, unit: Option<&'static str>) -> CheckTemperature {
+    CheckTemperature {
+        value: value,
+        unit: unit,
+        delta: true,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckTemperatureInterval {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckTemperatureInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TemperatureIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max && v.unit == self.unit
+                && v.min_inclusive == self.min_inclusive && v.max_inclusive == self.max_inclusive)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_temperature_interval(min: Option<f32>, max: Option<f32>, min_inclusive: bool, max_inclusive: bool, unit: Option<&'static str>) -> CheckTemperatureInterval {
+    CheckTemperatureInterval {
+        min: min,
+        max: max,
+        min_inclusive: min_inclusive,
+        max_inclusive: max_inclusive,
+        unit: unit,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckDistance {
+    pub value: f32,
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckDistance {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DistanceValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit && v.precision == self.precision)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_distance(value: f32, unit: Option<&'static str>, precision: Precision) -> CheckDistance {
+    CheckDistance {
+        value: value,
+        unit: unit,
+        precision: precision,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckQuantity {
+    pub value: f32,
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+    pub product: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckQuantity {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        QuantityValue::attempt_from(pn.value.clone())
+            .map(|v| (v.value - self.value).abs() < 0.01 && v.unit == self.unit && v.precision == self.precision
+                && v.product.as_ref().map(|p| p.as_str()) == self.product)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_quantity(value: f32, unit: Option<&'static str>, precision: Precision, product: Option<&'static str>) -> CheckQuantity {
+    CheckQuantity {
+        value: value,
+        unit: unit,
+        precision: precision,
+        product: product,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckRecurrence {
+    pub rrule: &'static str,
+    pub times: Option<i64>,
+    pub until: Option<Moment<Tz>>,
+    pub context: ResolverContext,
+}
+
+impl Check<Dimension> for CheckRecurrence {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        self.context.resolve(&pn.value)
+            .and_then(|v| RecurrenceOutput::attempt_from(v))
+            .map(|v| {
+                let rrule = v.to_rrule();
+                let rule = rrule.split(";UNTIL=").next().unwrap_or("");
+                rule == self.rrule && v.times == self.times && v.until == self.until
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_recurrence(context: ResolverContext, rrule: &'static str, times: Option<i64>, until: Option<Moment<Tz>>) -> CheckRecurrence {
+    CheckRecurrence {
+        rrule: rrule,
+        times: times,
+        until: until,
+        context: context,
     }
 }
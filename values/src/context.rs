@@ -27,17 +27,261 @@ impl<V: Value+Clone> ParsingContext<V> for IdentityContext<V> {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+/// Which occurrence of a time is picked when the text does not tell, "monday" or "in march".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ResolutionPolicy {
+    /// The next occurrence, falling back to the previous one.
+    PreferFuture,
+    /// The previous occurrence, falling back to the next one. The occurrence containing the
+    /// reference time ("today", "this week") is still preferred.
+    PreferPast,
+    /// The occurrence the closest to the reference time.
+    Nearest,
+}
+
+impl Default for ResolutionPolicy {
+    fn default() -> ResolutionPolicy {
+        ResolutionPolicy::PreferFuture
+    }
+}
+
+/// Resolves parsed dimensions against a reference time. Time values are resolved in the
+/// time zone of the reference moment.
+///
+/// Contexts are not `Copy` since they hold the holidays of their business calendar, clone one
+/// to derive several contexts from it.
+#[derive(Debug, Clone)]
 pub struct ResolverContext {
-    ctx: Context<Local>,
+    ctx: Context<Tz>,
+    policy: ResolutionPolicy,
+}
+
+impl Default for ResolverContext {
+    fn default() -> ResolverContext {
+        ResolverContext::for_timezone(Tz::Local)
+    }
 }
 
 impl ResolverContext {
-    pub fn new(now: Interval<Local>) -> ResolverContext {
+    pub fn new(now: Interval<Tz>) -> ResolverContext {
         ResolverContext {
-           ctx: Context::for_reference(now) 
+           ctx: Context::for_reference(now),
+           policy: ResolutionPolicy::default(),
         }
     }
+
+    /// Context resolving times with the given policy.
+    pub fn with_policy(self, policy: ResolutionPolicy) -> ResolverContext {
+        ResolverContext { policy: policy, .. self }
+    }
+
+    pub fn policy(&self) -> ResolutionPolicy {
+        self.policy
+    }
+
+    /// Context searching the times from `years_before` years before the reference time to
+    /// `years_after` years after it. By default, the whole range of dates supported is searched.
+    pub fn with_search_window(mut self, years_before: u32, years_after: u32) -> ResolverContext {
+        self.ctx = self.ctx.with_window(years_before, years_after);
+        self
+    }
+
+    /// Context whose weeks start on `week_start`, for "this week" or "next week".
+    pub fn with_week_start(mut self, week_start: Weekday) -> ResolverContext {
+        self.ctx.week_start = week_start;
+        self
+    }
+
+    pub fn week_start(&self) -> Weekday {
+        self.ctx.week_start
+    }
+
+    /// Context reading numeric dates like "3/4/2015" with `order`, whatever the language.
+    pub fn with_date_order(mut self, order: DateOrder) -> ResolverContext {
+        self.ctx.date_order = Some(order);
+        self
+    }
+
+    /// Context whose seasons are the ones of `hemisphere`, "summer" being in december in the
+    /// southern hemisphere.
+    pub fn with_hemisphere(mut self, hemisphere: Hemisphere) -> ResolverContext {
+        self.ctx.hemisphere = hemisphere;
+        self
+    }
+
+    pub fn with_season_calendar(mut self, calendar: SeasonCalendar) -> ResolverContext {
+        self.ctx.season_calendar = calendar;
+        self
+    }
+
+    /// Context whose business days, "in 3 business days" or "the last business day of the month",
+    /// also skip `holidays`, in place of the holidays previously given.
+    pub fn with_holidays(mut self, holidays: Vec<NaiveDate>) -> ResolverContext {
+        self.ctx.business_calendar = self.ctx.business_calendar.with_holidays(holidays);
+        self
+    }
+
+    /// Context whose weekend days, skipped by the business days, are `weekend` instead of
+    /// saturday and sunday.
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> ResolverContext {
+        self.ctx.business_calendar = self.ctx.business_calendar.with_weekend(weekend);
+        self
+    }
+
+    /// Context giving the hours of a part of the day or of a meal, from `start` to `end`
+    /// excluded on a 24h clock. The other parts keep the hours of the grammar.
+    pub fn with_part_of_day_hours(mut self, part: PartOfDay, start: u32, end: u32) -> ResolverContext {
+        self.ctx.part_of_day_hours.set(part, start, end);
+        self
+    }
+
+    /// Context using the current time in the given time zone as reference.
+    pub fn for_timezone(tz: Tz) -> ResolverContext {
+        ResolverContext::new(Interval::starting_at(Moment::now_in(tz), Grain::Second))
+    }
+
+    pub fn reference(&self) -> Interval<Tz> {
+        self.ctx.reference
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.ctx.reference.start.timezone()
+    }
+
+    /// Resolves a dimension into at most `n` outputs, best first. The first one is the output of
+    /// `resolve`; time values then alternate between the next and the previous matching
+    /// intervals, starting with the direction preferred by the policy.
+    pub fn resolve_n(&self, dim: &Dimension, n: usize) -> Vec<Output> {
+        match dim {
+            &Dimension::Time(ref tv) => {
+                self.time_intervals(tv, n)
+                    .into_iter()
+                    .map(|interval| time_output(tv, interval))
+                    .collect()
+            }
+            _ => self.resolve(dim).into_iter().take(n).collect(),
+        }
+    }
+
+    /// Intervals of a time value intersecting `window`, in chronological order. They are
+    /// generated lazily, "every monday" over a year does not compute all the mondays upfront.
+    pub fn occurrences(&self, tv: &TimeValue, window: Interval<Tz>) -> walker::Walker<Interval<Tz>> {
+        tv.constraint.walk_within(window, &self.ctx)
+    }
+
+    fn resolve_recurrence(&self, recurrence: &RecurrenceValue) -> Option<RecurrenceOutput> {
+        let until = match recurrence.until {
+            Some(ref until) => Some(self.time_intervals(until, 1).into_iter().next()?.end_moment()),
+            None => None,
+        };
+        let (frequency, first) = match recurrence.time {
+            Some(ref time) => {
+                let occurrences = self.time_intervals(time, 2);
+                let first = *occurrences.first()?;
+                // Without explicit cycle, the frequency is the distance between two occurrences
+                let frequency = recurrence.cycle.or_else(|| {
+                    occurrences.get(1).and_then(|other| {
+                        let (a, b) = if first.start <= other.start { (first, *other) } else { (*other, first) };
+                        [Grain::Second, Grain::Minute, Grain::Hour, Grain::Day, Grain::Week, Grain::Month, Grain::Quarter, Grain::Year]
+                            .iter()
+                            .find(|grain| a.start + PeriodComp::new(**grain, 1) == b.start)
+                            .cloned()
+                    })
+                })?;
+                (frequency, Some(first))
+            }
+            None => (recurrence.cycle?, None),
+        };
+        Some(RecurrenceOutput {
+            frequency: frequency,
+            interval: recurrence.interval,
+            times: recurrence.times,
+            start: first.map(|i| i.start),
+            grain: first.map(|i| i.grain).unwrap_or(frequency),
+            until: until,
+        })
+    }
+
+    /// Matching intervals of a time value, ordered by the resolution policy.
+    fn time_intervals(&self, tv: &TimeValue, n: usize) -> Vec<Interval<Tz>> {
+        let reference = self.ctx.reference;
+        let mut walker = tv.constraint
+            .to_walker(&reference, &self.ctx);
+        let mut next = walker.forward.next();
+        // Only walked when an interval before the reference may be picked
+        let mut backward = walker.backward.peekable();
+        let mut intervals = vec![];
+        if next.map(|h| h.intersect(reference).is_some()).unwrap_or(false) {
+            if tv.form.not_immediate().unwrap_or(false) {
+                next = walker.forward.next();
+            } else if self.policy == ResolutionPolicy::PreferPast {
+                intervals.extend(next);
+                next = walker.forward.next();
+            }
+        }
+        let prefer_next = self.policy != ResolutionPolicy::PreferPast;
+        while intervals.len() < n {
+            // Alternates between both directions, starting with the preferred one
+            let alternate = (intervals.len() % 2 == 0) == prefer_next;
+            let take_next = match next {
+                None => false,
+                Some(_) if self.policy != ResolutionPolicy::Nearest && alternate => true,
+                Some(a) => match backward.peek().cloned() {
+                    None => true,
+                    Some(b) => self.policy == ResolutionPolicy::Nearest
+                        && a.start.timestamp() - reference.start.timestamp()
+                            <= reference.start.timestamp() - b.end_moment().timestamp(),
+                },
+            };
+            if take_next {
+                intervals.extend(next);
+                next = walker.forward.next();
+            } else if let Some(previous) = backward.next() {
+                intervals.push(previous);
+            } else {
+                break;
+            }
+        }
+        intervals
+    }
+}
+
+fn time_output(tv: &TimeValue, interval: Interval<Tz>) -> Output {
+    if let Some(bounded_direction) = tv.direction {
+        let anchor = match bounded_direction.bound {
+            Bound::Start => interval.start,
+            Bound::End => interval.end.unwrap_or(interval.start),
+        };
+
+        let output = TimeOutput {
+            moment: anchor,
+            grain: interval.grain,
+            precision: tv.precision,
+            latent: tv.latent,
+        };
+
+        match bounded_direction.direction {
+            Direction::After => Output::TimeInterval(TimeIntervalOutput::After(output)),
+            Direction::Before => Output::TimeInterval(TimeIntervalOutput::Before(output)),
+        }
+    } else if let Some(end) = interval.end {
+        Output::TimeInterval(
+                TimeIntervalOutput::Between {
+                    start: interval.start,
+                    end: end,
+                    precision: tv.precision,
+                    latent: tv.latent,
+                }
+            )
+    } else {
+        let output = TimeOutput {
+                moment: interval.start,
+                grain: interval.grain,
+                precision: tv.precision,
+                latent: tv.latent,
+        };
+        Output::Time(output)
+    }
 }
 
 impl ParsingContext<Dimension> for ResolverContext {
@@ -45,55 +289,10 @@ impl ParsingContext<Dimension> for ResolverContext {
     fn resolve(&self, dim: &Dimension) -> Option<Output> {
         match dim {
             &Dimension::Time(ref tv) => {
-                let mut walker = tv.constraint
-                    .to_walker(&self.ctx.reference, &self.ctx);
-                walker.forward
+                self.time_intervals(tv, 1)
+                    .into_iter()
                     .next()
-                    .and_then(|h| {
-                        if tv.form.not_immediate().unwrap_or(false) && h.intersect(self.ctx.reference).is_some() {
-                            walker.forward.next()
-                        } else {
-                            Some(h)
-                        }
-                    })
-                    .or_else(|| walker.backward.next())
-                    .map(|interval| {
-                        if let Some(bounded_direction) = tv.direction {
-                            let anchor = match bounded_direction.bound {
-                                Bound::Start => interval.start,
-                                Bound::End => interval.end.unwrap_or(interval.start),
-                            };
-                            
-                            let output = TimeOutput {
-                                moment: anchor,
-                                grain: interval.grain,
-                                precision: tv.precision,
-                                latent: tv.latent,
-                            };
-                            
-                            match bounded_direction.direction {
-                                Direction::After => Output::TimeInterval(TimeIntervalOutput::After(output)),
-                                Direction::Before => Output::TimeInterval(TimeIntervalOutput::Before(output)),
-                            }
-                        } else if let Some(end) = interval.end {
-                            Output::TimeInterval(
-                                    TimeIntervalOutput::Between {
-                                        start: interval.start, 
-                                        end: end, 
-                                        precision: tv.precision,
-                                        latent: tv.latent,
-                                    }
-                                )
-                        } else {
-                            let output = TimeOutput {
-                                    moment: interval.start,
-                                    grain: interval.grain,
-                                    precision: tv.precision,
-                                    latent: tv.latent,
-                            };
-                            Output::Time(output)
-                        }
-                    })
+                    .map(|interval| time_output(tv, interval))
             }
             &Dimension::Number(ref number) => {
                 match number {
@@ -101,24 +300,119 @@ impl ParsingContext<Dimension> for ResolverContext {
                     &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
                 }
             }
+            &Dimension::NumberRange(ref range) => Some(Output::NumberRange(NumberRangeOutput {
+                min: range.min,
+                max: range.max,
+                min_inclusive: range.min_inclusive,
+                max_inclusive: range.max_inclusive,
+            })),
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
             &Dimension::AmountOfMoney(ref aom) => Some(Output::AmountOfMoney(AmountOfMoneyOutput {
                 value: aom.value,
                 precision: aom.precision,
                 unit: aom.unit,
             })),
+            &Dimension::AmountOfMoneyInterval(ref interval) => {
+                let bound = |value| AmountOfMoneyOutput {
+                    value: value,
+                    precision: interval.precision,
+                    unit: interval.unit,
+                };
+                let output = match (interval.min, interval.max) {
+                    (Some(min), None) if interval.min_inclusive => AmountOfMoneyIntervalOutput::AtLeast(bound(min)),
+                    (Some(min), None) => AmountOfMoneyIntervalOutput::After(bound(min)),
+                    (None, Some(max)) if interval.max_inclusive => AmountOfMoneyIntervalOutput::AtMost(bound(max)),
+                    (None, Some(max)) => AmountOfMoneyIntervalOutput::Before(bound(max)),
+                    (Some(min), Some(max)) => AmountOfMoneyIntervalOutput::Between {
+                        min: min,
+                        max: max,
+                        precision: interval.precision,
+                        unit: interval.unit,
+                    },
+                    (None, None) => return None,
+                };
+                Some(Output::AmountOfMoneyInterval(output))
+            }
             &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
                 value: temp.value,
                 unit: temp.unit,
                 latent: temp.latent,
+                delta: temp.delta,
+            })),
+            &Dimension::TemperatureInterval(ref interval) => {
+                let bound = |value| TemperatureOutput {
+                    value: value,
+                    unit: interval.unit,
+                    latent: false,
+                    delta: false,
+                };
+                let output = match (interval.min, interval.max) {
+                    (Some(min), None) if interval.min_inclusive => TemperatureIntervalOutput::AtLeast(bound(min)),
+                    (Some(min), None) => TemperatureIntervalOutput::After(bound(min)),
+                    (None, Some(max)) if interval.max_inclusive => TemperatureIntervalOutput::AtMost(bound(max)),
+                    (None, Some(max)) => TemperatureIntervalOutput::Before(bound(max)),
+                    (Some(min), Some(max)) => TemperatureIntervalOutput::Between {
+                        min: min,
+                        max: max,
+                        unit: interval.unit,
+                    },
+                    (None, None) => return None,
+                };
+                Some(Output::TemperatureInterval(output))
+            }
+            &Dimension::Distance(ref distance) => Some(Output::Distance(DistanceOutput {
+                value: distance.value,
+                unit: distance.unit,
+                precision: distance.precision,
+                latent: distance.latent,
+            })),
+            &Dimension::Quantity(ref quantity) => Some(Output::Quantity(QuantityOutput {
+                value: quantity.value,
+                unit: quantity.unit,
+                family: quantity.family,
+                precision: quantity.precision,
+                product: quantity.product.clone(),
             })),
             &Dimension::Duration(ref duration) => Some(Output::Duration(DurationOutput {
                 period: duration.period.clone(),
                 precision: duration.precision,
             })),
             &Dimension::Percentage(ref percentage) => Some(Output::Percentage(PercentageOutput(percentage.0))),
+            &Dimension::Recurrence(ref recurrence) => self.resolve_recurrence(recurrence).map(Output::Recurrence),
             _ => None,
         }
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers;
+
+    #[test]
+    fn test_occurrences() {
+        let moment = |y, m, d, h| Moment(Tz::Local.ymd(y, m, d).and_hms(h, 0, 0));
+        let context = ResolverContext::new(Interval::starting_at(moment(2013, 2, 12, 4), Grain::Second));
+        let monday = helpers::day_of_week(Weekday::Mon).unwrap();
+        let starts = |window| {
+            context.occurrences(&monday, window)
+                .map(|interval| interval.start)
+                .collect::<Vec<_>>()
+        };
+
+        // Mondays intersecting the window are kept, even when they start before it or end after it
+        let window = Interval { start: moment(2013, 2, 11, 12), grain: Grain::Second, end: Some(moment(2013, 3, 4, 12)) };
+        assert_eq!(vec![moment(2013, 2, 11, 0), moment(2013, 2, 18, 0), moment(2013, 2, 25, 0), moment(2013, 3, 4, 0)],
+                   starts(window));
+
+        // Bounds falling at midnight exclude the adjacent mondays
+        let window = Interval { start: moment(2013, 2, 12, 0), grain: Grain::Second, end: Some(moment(2013, 3, 4, 0)) };
+        assert_eq!(vec![moment(2013, 2, 18, 0), moment(2013, 2, 25, 0)], starts(window));
+
+        // Occurrences are generated lazily, over a window of a century
+        let window = Interval { start: moment(2013, 2, 12, 0), grain: Grain::Second, end: Some(moment(2113, 2, 12, 0)) };
+        let limited = context.occurrences(&monday, window).take(3).map(|interval| interval.start).collect::<Vec<_>>();
+        assert_eq!(vec![moment(2013, 2, 18, 0), moment(2013, 2, 25, 0), moment(2013, 3, 4, 0)], limited);
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt, result};
 
 use rustling::*;
-use moment::{RcConstraint, Period, Grain, Local};
+use moment::{RcConstraint, Period, Grain, Tz};
 
 /// Union of all possible values parsed by the ontology.
 rustling_value! {
@@ -9,47 +9,68 @@ rustling_value! {
     #[derive(Clone,PartialEq,Debug)]
     Dimension DimensionKind {
         Number(NumberValue),
+        NumberRange(NumberRangeValue),
         AmountOfMoney(AmountOfMoneyValue),
+        AmountOfMoneyInterval(AmountOfMoneyIntervalValue),
         Ordinal(OrdinalValue),
         Temperature(TemperatureValue),
+        TemperatureInterval(TemperatureIntervalValue),
+        Distance(DistanceValue),
+        Quantity(QuantityValue),
         MoneyUnit(MoneyUnitValue),
+        QuantityUnit(QuantityUnitValue),
         Time(TimeValue),
         Duration(DurationValue),
         Percentage(PercentageValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
+        Recurrence(RecurrenceValue),
     }
 
     fn latent(v: &Dimension) -> bool {
         match v {
             &Dimension::Number(_) => false,
+            &Dimension::NumberRange(_) => false,
             &Dimension::Percentage(_) => false,
             &Dimension::AmountOfMoney(_) => false,
+            &Dimension::AmountOfMoneyInterval(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(ref temp) => temp.latent,
+            &Dimension::TemperatureInterval(_) => false,
+            &Dimension::Distance(ref distance) => distance.latent,
+            &Dimension::Quantity(_) => false,
             &Dimension::MoneyUnit(_) => true,
+            &Dimension::QuantityUnit(_) => true,
             &Dimension::Time(ref tv) => tv.latent,
             &Dimension::Duration(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
+            &Dimension::Recurrence(_) => false,
         }
     }
 
     fn extract_payload(v: &Dimension) -> Option<Payload> {
         match v {
             &Dimension::Number(_) => None,
+            &Dimension::NumberRange(_) => None,
             &Dimension::Percentage(_) => None,
             &Dimension::AmountOfMoney(_) => None,
+            &Dimension::AmountOfMoneyInterval(_) => None,
             &Dimension::Ordinal(_) => None,
             &Dimension::Temperature(_) => None,
+            &Dimension::TemperatureInterval(_) => None,
+            &Dimension::Distance(_) => None,
+            &Dimension::Quantity(_) => None,
             &Dimension::MoneyUnit(_) => None,
+            &Dimension::QuantityUnit(_) => None,
             &Dimension::Time(ref tv) => Some(Payload(tv.constraint.grain())),
             &Dimension::Duration(_) => None,
             &Dimension::Cycle(_) => None,
             &Dimension::UnitOfDuration(_) => None,
             &Dimension::RelativeMinute(_) => None,
+            &Dimension::Recurrence(_) => None,
         }
     }
 }
@@ -66,16 +87,23 @@ impl fmt::Display for Dimension {
                     &NumberValue::Float(ref v) => write!(fmt, "Number: {}", v.value),
                 }
             }
+            &Dimension::NumberRange(_) => write!(fmt, "NumberRange"),
             &Dimension::Percentage(ref v) => write!(fmt, "Percentage: {}", v.0),
             &Dimension::Ordinal(_) => write!(fmt, "Ordinal"),
             &Dimension::Temperature(_) => write!(fmt, "Temperature"),
+            &Dimension::TemperatureInterval(_) => write!(fmt, "TemperatureInterval"),
+            &Dimension::Distance(_) => write!(fmt, "Distance"),
+            &Dimension::Quantity(_) => write!(fmt, "Quantity"),
             &Dimension::AmountOfMoney(_) => write!(fmt, "AmountOfMoney"),
+            &Dimension::AmountOfMoneyInterval(_) => write!(fmt, "AmountOfMoneyInterval"),
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
+            &Dimension::QuantityUnit(_) => write!(fmt, "QuantityUnit"),
             &Dimension::Time(_) => write!(fmt, "Time"),
             &Dimension::Duration(_) => write!(fmt, "Duration"),
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
             &Dimension::UnitOfDuration(_) => write!(fmt, "UnitOfDuration"),
             &Dimension::RelativeMinute(_) => write!(fmt, "RelativeMinute"),
+            &Dimension::Recurrence(_) => write!(fmt, "Recurrence"),
         }
     }
 }
@@ -103,7 +131,8 @@ impl OrdinalValue {
     }
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Precision {
     Approximate,
     Exact,
@@ -123,6 +152,29 @@ pub struct AmountOfMoneyValue {
     pub unit: Option<&'static str>,
 }
 
+/// Payload for the bounded amounts of money of Dimension, "under $50" or "between 20 and 30
+/// euros". A missing bound leaves the interval open on its side.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct AmountOfMoneyIntervalValue {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+    pub precision: Precision,
+    pub unit: Option<&'static str>,
+}
+
+impl AmountOfMoneyIntervalValue {
+    /// Direction of an interval open on one side, `After` for "over $50".
+    pub fn direction(&self) -> Option<Direction> {
+        match (self.min, self.max) {
+            (Some(_), None) => Some(Direction::After),
+            (None, Some(_)) => Some(Direction::Before),
+            _ => None,
+        }
+    }
+}
+
 /// Payload for the unit of money of Dimension
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct MoneyUnitValue {
@@ -310,6 +362,16 @@ impl NumberValue {
     }
 }
 
+/// Payload for the number ranges of Dimension, "between 3 and 5" or "at least 5". A missing
+/// bound leaves the range open on its side, and is never inclusive.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct NumberRangeValue {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+}
+
 /// Payload for the temperatures of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct TemperatureValue {
@@ -318,6 +380,77 @@ pub struct TemperatureValue {
     pub unit: Option<&'static str>,
     /// true if it can not be confirmed that the value is actually a temperature
     pub latent: bool,
+    /// true if the value is a change of temperature, "two degrees warmer"
+    pub delta: bool,
+}
+
+/// Payload for the temperature intervals of Dimension, "between 18 and 21 degrees" or "above 30
+/// degrees". A missing bound leaves the interval open on its side.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct TemperatureIntervalValue {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+    pub unit: Option<&'static str>,
+}
+
+impl TemperatureIntervalValue {
+    /// Direction of an interval open on one side, `Before` for "below 5 degrees".
+    pub fn direction(&self) -> Option<Direction> {
+        match (self.min, self.max) {
+            (Some(_), None) => Some(Direction::After),
+            (None, Some(_)) => Some(Direction::Before),
+            _ => None,
+        }
+    }
+}
+
+/// Payload for the distances of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct DistanceValue {
+    pub value: f32,
+    /// Metre, mile, ...
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+    /// true if it can not be confirmed that the value is actually a distance
+    pub latent: bool,
+}
+
+impl DistanceValue {
+    pub fn unit(&self, unit: &'static str) -> DistanceValue {
+        DistanceValue {
+            unit: Some(unit),
+            latent: false,
+            .. self.clone()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuantityFamily {
+    Mass,
+    Volume,
+}
+
+/// Payload for the masses and volumes of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct QuantityValue {
+    pub value: f32,
+    /// Kilogram, cup, ...
+    pub unit: Option<&'static str>,
+    pub family: QuantityFamily,
+    pub precision: Precision,
+    /// What is measured, "sugar" in "a tablespoon of sugar"
+    pub product: Option<String>,
+}
+
+/// Payload for the unit of mass or volume of Dimension
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct QuantityUnitValue {
+    pub unit: &'static str,
+    pub family: QuantityFamily,
 }
 
 /// Payload for the cycle of Dimension
@@ -344,31 +477,65 @@ impl UnitOfDurationValue {
     }
 }
 
+/// Payload for the recurrences of Dimension, like "every other monday" or "twice a week"
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecurrenceValue {
+    /// Repeated time, "monday at 3pm" in "every monday at 3pm"
+    pub time: Option<TimeValue>,
+    /// Period of the recurrence when given by the text, the week in "twice a week"
+    pub cycle: Option<Grain>,
+    /// Number of periods between two occurrences, 2 in "every other day"
+    pub interval: i64,
+    /// Number of occurrences per period, 2 in "twice a week"
+    pub times: Option<i64>,
+    /// End of the recurrence, "june" in "every monday until june"
+    pub until: Option<TimeValue>,
+}
+
+impl RecurrenceValue {
+    pub fn every_cycle(grain: Grain) -> RecurrenceValue {
+        RecurrenceValue {
+            time: None,
+            cycle: Some(grain),
+            interval: 1,
+            times: None,
+            until: None,
+        }
+    }
+
+    pub fn every_time(time: TimeValue) -> RecurrenceValue {
+        RecurrenceValue {
+            time: Some(time),
+            cycle: None,
+            interval: 1,
+            times: None,
+            until: None,
+        }
+    }
+
+    pub fn times_per_cycle(times: i64, grain: Grain) -> RecurrenceValue {
+        RecurrenceValue {
+            times: Some(times),
+            .. RecurrenceValue::every_cycle(grain)
+        }
+    }
+
+    pub fn interval(self, interval: i64) -> RecurrenceValue {
+        RecurrenceValue { interval: interval, .. self }
+    }
+}
+
 /// Payload for the time of Dimension
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TimeValue {
-    pub constraint: RcConstraint<Local>,
+    pub constraint: RcConstraint<Tz>,
     pub form: Form,
     pub direction: Option<BoundedDirection>,
     pub precision: Precision,
     pub latent: bool,
 }
 
-// We need partial eq to make Dimension partial eq happy, but this is only
-// useful for testing.
-impl PartialEq for TimeValue {
-    fn eq(&self, _other: &TimeValue) -> bool {
-        unimplemented!()
-    }
-}
-
-impl ::std::fmt::Debug for TimeValue {
-    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
-        write!(fmt, "<TimeValue>")
-    }
-}
-
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Form {
     Cycle(Grain),
     Year(i32),
@@ -407,19 +574,19 @@ impl Form {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     After,
     Before,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Bound {
     Start,
     End,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BoundedDirection {
     pub bound: Bound,
     pub direction: Direction, 
@@ -455,7 +622,7 @@ impl BoundedDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PartOfDayForm {
     Morning,
     Afternoon,
@@ -464,7 +631,7 @@ pub enum PartOfDayForm {
     None,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TimeOfDayForm {
     Hour { full_hour: u32, is_12_clock: bool },
     HourMinute {  full_hour: u32, minute: u32, is_12_clock: bool },
@@ -506,13 +673,13 @@ impl TimeOfDayForm {
 }
 
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MonthDayForm {
     pub month: u32,
     pub day_of_month: u32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct YearMonthDayForm {
     pub year: i32,
     pub month: u32,
@@ -23,6 +23,37 @@ pub struct RegexMatch<'a> {
     pub groups: Vec<Option<&'a str>>,
 }
 
+/// Range from `min` to `max` included, "between 3 and 5" or "10-20".
+pub fn number_range_between(min: &NumberValue, max: &NumberValue) -> RuleResult<NumberRangeValue> {
+    if min.value() >= max.value() {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(NumberRangeValue {
+           min: Some(min.value()),
+           max: Some(max.value()),
+           min_inclusive: true,
+           max_inclusive: true,
+       })
+}
+
+/// Range open above, "at least 5" or "more than 5".
+pub fn number_range_from(min: &NumberValue, inclusive: bool) -> RuleResult<NumberRangeValue> {
+    Ok(NumberRangeValue {
+           min: Some(min.value()),
+           min_inclusive: inclusive,
+           ..NumberRangeValue::default()
+       })
+}
+
+/// Range open below, "up to 10" or "less than 10".
+pub fn number_range_to(max: &NumberValue, inclusive: bool) -> RuleResult<NumberRangeValue> {
+    Ok(NumberRangeValue {
+           max: Some(max.value()),
+           max_inclusive: inclusive,
+           ..NumberRangeValue::default()
+       })
+}
+
 pub fn find_regex_group<'a>(regex: &Regex, sentence: &'a str) -> RuleResult<Vec<RegexMatch<'a>>> {
     let mut matches = Vec::new();
     for cap in regex.captures_iter(&sentence) {
@@ -65,6 +96,153 @@ pub fn compose_money_number(a: &AmountOfMoneyValue,
        })
 }
 
+/// Amounts of money from `min` to `max`, "between $20 and $30". A bound without unit, "20" in
+/// "20 to 30 euros", takes the unit of the other one.
+pub fn money_between(min: &AmountOfMoneyValue, max: &AmountOfMoneyValue) -> RuleResult<AmountOfMoneyIntervalValue> {
+    if min.value >= max.value || (min.unit.is_some() && max.unit.is_some() && min.unit != max.unit) {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(AmountOfMoneyIntervalValue {
+           min: Some(min.value),
+           max: Some(max.value),
+           min_inclusive: true,
+           max_inclusive: true,
+           precision: precision_resolution(min.precision, max.precision),
+           unit: max.unit.or(min.unit),
+       })
+}
+
+/// Amounts of money above or below `money`, "over $50" or "under $50", or "at least $50" and
+/// "at most $50" when `inclusive`.
+pub fn money_bound(money: &AmountOfMoneyValue, direction: Direction, inclusive: bool) -> RuleResult<AmountOfMoneyIntervalValue> {
+    let (min, max) = match direction {
+        Direction::After => (Some(money.value), None),
+        Direction::Before => (None, Some(money.value)),
+    };
+    Ok(AmountOfMoneyIntervalValue {
+           min: min,
+           max: max,
+           min_inclusive: inclusive,
+           max_inclusive: inclusive,
+           precision: money.precision,
+           unit: money.unit,
+       })
+}
+
+/// Amounts of money in a range of numbers, "between 20 and 30 euros" or "20-30 euros".
+pub fn money_range(range: &NumberRangeValue, unit: &MoneyUnitValue) -> RuleResult<AmountOfMoneyIntervalValue> {
+    Ok(AmountOfMoneyIntervalValue {
+           min: range.min,
+           max: range.max,
+           min_inclusive: range.min_inclusive,
+           max_inclusive: range.max_inclusive,
+           unit: unit.unit,
+           ..AmountOfMoneyIntervalValue::default()
+       })
+}
+
+/// Temperatures from `min` to `max`, "between 18 and 21 degrees". A latent bound, "18" in this
+/// example, takes the unit of the other one.
+pub fn temperature_between(min: &TemperatureValue, max: &TemperatureValue) -> RuleResult<TemperatureIntervalValue> {
+    if (min.latent && max.latent) || min.delta || max.delta || min.value >= max.value
+        || (min.unit.is_some() && max.unit.is_some() && min.unit != max.unit) {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(TemperatureIntervalValue {
+           min: Some(min.value),
+           max: Some(max.value),
+           min_inclusive: true,
+           max_inclusive: true,
+           unit: max.unit.or(min.unit),
+       })
+}
+
+/// Temperatures above or below `temp`, "above 30 degrees" or "below 5 degrees", or "at least 30
+/// degrees" and "at most 5 degrees" when `inclusive`.
+pub fn temperature_bound(temp: &TemperatureValue, direction: Direction, inclusive: bool) -> RuleResult<TemperatureIntervalValue> {
+    if temp.latent || temp.delta {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    let (min, max) = match direction {
+        Direction::After => (Some(temp.value), None),
+        Direction::Before => (None, Some(temp.value)),
+    };
+    Ok(TemperatureIntervalValue {
+           min: min,
+           max: max,
+           min_inclusive: inclusive && min.is_some(),
+           max_inclusive: inclusive && max.is_some(),
+           unit: temp.unit,
+       })
+}
+
+/// Change of temperature by `sign` times `temp`, "two degrees warmer" with a positive sign.
+pub fn temperature_delta(temp: &TemperatureValue, sign: f32) -> RuleResult<TemperatureValue> {
+    if temp.latent || temp.delta {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(TemperatureValue {
+           value: sign * temp.value,
+           delta: true,
+           ..temp.clone()
+       })
+}
+
+/// Length of a unit of distance, in metres, and whether the unit is metric.
+fn distance_unit(unit: &str) -> Option<(f32, bool)> {
+    match unit {
+        "kilometre" => Some((1000.0, true)),
+        "metre" => Some((1.0, true)),
+        "centimetre" => Some((0.01, true)),
+        "millimetre" => Some((0.001, true)),
+        "mile" => Some((1609.344, false)),
+        "yard" => Some((0.9144, false)),
+        "foot" => Some((0.3048, false)),
+        "inch" => Some((0.0254, false)),
+        _ => None,
+    }
+}
+
+/// Composes a distance with a finer one of the same system, "6 ft 2 in" or "1 m 80 cm", into a
+/// distance in the finer unit.
+pub fn compose_distances(a: &DistanceValue, b: &DistanceValue) -> RuleResult<DistanceValue> {
+    let ratio = match (a.unit.and_then(distance_unit), b.unit.and_then(distance_unit)) {
+        (Some((coarse, coarse_metric)), Some((fine, fine_metric))) if coarse > fine && coarse_metric == fine_metric => {
+            (coarse / fine).round()
+        }
+        _ => return Err(RuleErrorKind::Invalid.into()),
+    };
+    if b.value >= ratio {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(DistanceValue {
+           value: a.value * ratio + b.value,
+           unit: b.unit,
+           precision: precision_resolution(a.precision, b.precision),
+           latent: false,
+       })
+}
+
+pub fn quantity(value: f32, unit: &QuantityUnitValue) -> RuleResult<QuantityValue> {
+    Ok(QuantityValue {
+           value: value,
+           unit: Some(unit.unit),
+           family: unit.family,
+           precision: Precision::Exact,
+           product: None,
+       })
+}
+
+/// Value of a written fraction, "3/4" in "3/4 cup".
+pub fn fraction(numerator: &str, denominator: &str) -> RuleResult<f32> {
+    let numerator: f32 = numerator.parse()?;
+    let denominator: f32 = denominator.parse()?;
+    if denominator == 0.0 {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    Ok(numerator / denominator)
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -132,7 +310,7 @@ fn from_addition_resolution(lhs: Option<FromAddition>, rhs: Option<FromAddition>
     }
 }
 impl TimeValue {
-    pub fn constraint(constraint: RcConstraint<Local>) -> TimeValue {
+    pub fn constraint(constraint: RcConstraint<Tz>) -> TimeValue {
         TimeValue {
             constraint: constraint,
             form: Form::Empty,
@@ -340,6 +518,11 @@ pub fn hour(h: u32, is_12_clock: bool) -> RuleResult<TimeValue> {
     }
 }
 
+/// Hours `start` to `end` of a part of the day, unless the resolver context gives other hours.
+pub fn part_of_day(part: PartOfDay, start: u32, end: u32) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(DayPart::new(part, start, end)))
+}
+
 pub fn minute(m: u32) -> RuleResult<TimeValue> {
     Ok(TimeValue::constraint(Minute::new(m)))
 }
@@ -420,16 +603,72 @@ pub fn cycle_n_not_immediate(grain: Grain, n: i64) -> RuleResult<TimeValue> {
     Ok(TimeValue::constraint(Cycle::rc(grain).take_not_immediate(n)).form(Form::Cycle(grain)))
 }
 
+impl RecurrenceValue {
+    /// Restricts the recurrence to a time, "at 9am" in "every day at 9am".
+    pub fn at(&self, time: &TimeValue) -> RuleResult<RecurrenceValue> {
+        if self.until.is_some() || time.direction.is_some() {
+            return Err(RuleErrorKind::Invalid.into());
+        }
+        let time = match self.time {
+            Some(ref current) => current.intersect(time)?,
+            None => time.clone(),
+        };
+        Ok(RecurrenceValue { time: Some(time), .. self.clone() })
+    }
+
+    /// Ends the recurrence with a time, "until june" in "every monday until june".
+    pub fn until(&self, time: &TimeValue) -> RuleResult<RecurrenceValue> {
+        if self.until.is_some() || time.direction.is_some() {
+            return Err(RuleErrorKind::Invalid.into());
+        }
+        Ok(RecurrenceValue { until: Some(time.clone()), .. self.clone() })
+    }
+}
+
+/// Season, with the hemisphere and the season calendar of the resolver context.
+pub fn season(season: Season) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(YearPart::new(season)))
+}
+
+/// Day `n` business days after today, "in 3 business days", or before today when `n` is
+/// negative. Weekend days and holidays are those of the resolver context.
+pub fn business_day_nth(n: i64) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(NthBusinessDay::new(n)))
+}
+
+pub fn business_day_nth_after(n: i64, after_value: &TimeValue) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(BusinessDay::rc().the_nth(n).after(&after_value.constraint)))
+}
+
+pub fn business_day_last_of(base: &TimeValue) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(BusinessDay::rc().last_of(&base.constraint)).precision(base.precision))
+}
+
+/// Numeric date like "3/4" or "3/4/2015", read with `order` unless the resolver context gives
+/// another date order.
+pub fn numeric_date(first: u32, second: u32, year: Option<i32>, order: DateOrder) -> RuleResult<TimeValue> {
+    let date = NumericDate { first: first, second: second, year: year, order: order };
+    if date.readings(DateOrder::Both).is_empty() {
+        return Err(RuleErrorKind::Invalid.into());
+    }
+    let value = TimeValue::constraint(NumericDate::new(first, second, year, order));
+    if year.is_some() {
+        Ok(value)
+    } else {
+        Ok(value.form(Form::MonthDay(None)))
+    }
+}
+
 pub fn ymd(y: i32, m: u32, d: u32) -> RuleResult<TimeValue> {
      Ok(TimeValue::constraint(YearMonthDay::new(y, m, d)))
 }
 
 pub fn easter() -> RuleResult<TimeValue> {
-    fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
+    fn offset(i: &Interval<Tz>, _: &Context<Tz>) -> Option<Interval<Tz>> {
         let (year, month, day) = computer_easter(i.start.year());
-        Some(Interval::ymd(year, month, day))
+        Some(Interval::starting_at(Moment(i.start.timezone().ymd(year, month, day).and_hms(0, 0, 0)), Grain::Day))
     }
-    Ok(TimeValue::constraint(Month::new(3).translate_with(offset)))
+    Ok(TimeValue::constraint(Month::new(3).translate_with("easter", &[], offset)))
 }
 
 pub fn computer_easter(year: i32) -> (i32, u32, u32) {
@@ -539,6 +778,20 @@ mod tests {
         assert_eq!(93, decimal_hour_in_minute("1", "55").unwrap());
     }
 
+    #[test]
+    fn test_compose_distances() {
+        let distance = |value: f32, unit: &'static str| DistanceValue {
+            value: value,
+            unit: Some(unit),
+            precision: Precision::Exact,
+            latent: false,
+        };
+        assert_eq!(74.0, compose_distances(&distance(6.0, "foot"), &distance(2.0, "inch")).unwrap().value);
+        assert_eq!(180.0, compose_distances(&distance(1.0, "metre"), &distance(80.0, "centimetre")).unwrap().value);
+        assert!(compose_distances(&distance(1.0, "kilometre"), &distance(2.0, "inch")).is_err());
+        assert!(compose_distances(&distance(2.0, "inch"), &distance(6.0, "foot")).is_err());
+    }
+
     #[test]
     fn test_computer_easter() {
         assert_eq!((2017, 4, 16), computer_easter(2017));
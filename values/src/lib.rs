@@ -2,6 +2,14 @@
 extern crate rustling;
 extern crate rustling_ontology_moment as moment;
 extern crate regex;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(all(test, feature = "serde"))]
+#[macro_use]
+extern crate serde_json;
 
 
 pub mod check;
@@ -13,8 +21,10 @@ pub mod macros_training;
 pub mod macros_rules;
 pub mod output;
 pub mod context;
+#[cfg(feature = "serde")]
+pub mod serialization;
 
 pub use dimension::Dimension;
 pub use dimension::DimensionKind;
 pub use output::Output;
-pub use context::{ResolverContext, ParsingContext, IdentityContext};
+pub use context::{ResolverContext, ResolutionPolicy, ParsingContext, IdentityContext};
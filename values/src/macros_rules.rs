@@ -27,6 +27,13 @@ macro_rules! number_check {
 }
 
 
+#[macro_export]
+macro_rules! number_range_check {
+    () => ( ::rustling::core::AnyNodePattern::<NumberRangeValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<NumberRangeValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! number_check_by_range {
     ($min:expr) => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| number.value() >= $min)]) );
@@ -56,6 +63,12 @@ macro_rules! amount_of_money_check {
 }
 
 
+#[macro_export]
+macro_rules! amount_of_money_interval_check {
+    () => ( ::rustling::core::AnyNodePattern::<AmountOfMoneyIntervalValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<AmountOfMoneyIntervalValue>::filter(vec![b!($predicate)]) );
+}
+
 #[macro_export]
 macro_rules! money_unit {
     () => ( ::rustling::core::AnyNodePattern::<MoneyUnitValue>::new() );
@@ -82,6 +95,29 @@ macro_rules! temperature_check {
     ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<TemperatureValue>::filter(vec![b!($predicate)]) );
 }
 
+#[macro_export]
+macro_rules! temperature_interval_check {
+    () => ( ::rustling::core::AnyNodePattern::<TemperatureIntervalValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<TemperatureIntervalValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! distance_check {
+    () => ( ::rustling::core::AnyNodePattern::<DistanceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<DistanceValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! quantity_check {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<QuantityValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! quantity_unit {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityUnitValue>::new() );
+}
+
 
 #[macro_export]
 macro_rules! time_check {
@@ -108,6 +144,13 @@ macro_rules! time_of_day_check_hour {
 }
 
 
+#[macro_export]
+macro_rules! recurrence_check {
+    () => ( ::rustling::core::AnyNodePattern::<RecurrenceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<RecurrenceValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! duration_check {
     () => ( ::rustling::core::AnyNodePattern::<DurationValue>::new() );
@@ -1,17 +1,26 @@
+use rustling::ParserMatch;
 use moment::*;
 use dimension::*;
 
 #[derive(Clone,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Output {
     Integer(IntegerOutput),
     Float(FloatOutput),
+    NumberRange(NumberRangeOutput),
     Percentage(PercentageOutput),
     Ordinal(OrdinalOutput),
     Time(TimeOutput),
     TimeInterval(TimeIntervalOutput),
     AmountOfMoney(AmountOfMoneyOutput),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalOutput),
     Temperature(TemperatureOutput),
+    TemperatureInterval(TemperatureIntervalOutput),
+    Distance(DistanceOutput),
+    Quantity(QuantityOutput),
     Duration(DurationOutput),
+    Recurrence(RecurrenceOutput),
 }
 
 impl Output {
@@ -19,26 +28,39 @@ impl Output {
         match self {
             &Output::Integer(_) => OutputKind::Number,
             &Output::Float(_) => OutputKind::Number,
+            &Output::NumberRange(_) => OutputKind::NumberRange,
             &Output::Ordinal(_) => OutputKind::Ordinal,
             &Output::Time(_) => OutputKind::Time,
             &Output::TimeInterval(_) => OutputKind::Time,
             &Output::AmountOfMoney(_) => OutputKind::AmountOfMoney,
+            &Output::AmountOfMoneyInterval(_) => OutputKind::AmountOfMoneyInterval,
             &Output::Temperature(_) => OutputKind::Temperature,
+            &Output::TemperatureInterval(_) => OutputKind::TemperatureInterval,
+            &Output::Distance(_) => OutputKind::Distance,
+            &Output::Quantity(_) => OutputKind::Quantity,
             &Output::Duration(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::Recurrence(_) => OutputKind::Recurrence,
         }
     }
 }
 
+// By decreasing priority, a kind wins over the ones listed after it on the same span.
 enum_kind!(OutputKind,
     [
         Number,
         Ordinal,
         Time,
         AmountOfMoney,
+        AmountOfMoneyInterval,
         Temperature,
+        TemperatureInterval,
+        Distance,
+        Quantity,
         Duration,
-        Percentage
+        Percentage,
+        NumberRange,
+        Recurrence
     ]
 );
 
@@ -46,69 +68,281 @@ impl OutputKind {
     pub fn to_dim(&self) -> DimensionKind {
         match self {
             &OutputKind::Number => DimensionKind::Number,
+            &OutputKind::NumberRange => DimensionKind::NumberRange,
             &OutputKind::Ordinal => DimensionKind::Ordinal,
             &OutputKind::Time => DimensionKind::Time,
             &OutputKind::AmountOfMoney => DimensionKind::AmountOfMoney,
+            &OutputKind::AmountOfMoneyInterval => DimensionKind::AmountOfMoneyInterval,
             &OutputKind::Temperature => DimensionKind::Temperature,
+            &OutputKind::TemperatureInterval => DimensionKind::TemperatureInterval,
+            &OutputKind::Distance => DimensionKind::Distance,
+            &OutputKind::Quantity => DimensionKind::Quantity,
             &OutputKind::Duration => DimensionKind::Duration,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::Recurrence => DimensionKind::Recurrence,
         }
     }
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegerOutput(pub i64);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FloatOutput(pub f32);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NumberRangeOutput {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub min_inclusive: bool,
+    pub max_inclusive: bool,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PercentageOutput(pub f32);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OrdinalOutput(pub i64);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeOutput {
-    pub moment: Moment<Local>, 
+    pub moment: Moment<Tz>, 
     pub grain: Grain, 
     pub precision: Precision,
     pub latent: bool,
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimeIntervalOutput {
     After(TimeOutput),
     Before(TimeOutput),
-    Between { start: Moment<Local>, end: Moment<Local>, precision: Precision, latent: bool }
+    Between { start: Moment<Tz>, end: Moment<Tz>, precision: Precision, latent: bool }
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AmountOfMoneyOutput {
     pub value: f32, 
     pub precision: Precision, 
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
     pub unit: Option<&'static str>,
 }
 
+/// Amounts of money above ("over $50"), below ("under $50") or between two bounds included.
+/// `AtLeast` and `AtMost` include their bound ("at least $50", "at most $50").
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AmountOfMoneyIntervalOutput {
+    After(AmountOfMoneyOutput),
+    Before(AmountOfMoneyOutput),
+    AtLeast(AmountOfMoneyOutput),
+    AtMost(AmountOfMoneyOutput),
+    Between {
+        min: f32,
+        max: f32,
+        precision: Precision,
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
+        unit: Option<&'static str>,
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TemperatureOutput {
     pub value: f32, 
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
     pub unit: Option<&'static str>,
     pub latent: bool,
+    pub delta: bool,
+}
+
+/// Temperatures above ("above 30 degrees"), below ("below 5 degrees") or between two bounds
+/// included. `AtLeast` and `AtMost` include their bound ("at least 30 degrees").
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TemperatureIntervalOutput {
+    After(TemperatureOutput),
+    Before(TemperatureOutput),
+    AtLeast(TemperatureOutput),
+    AtMost(TemperatureOutput),
+    Between {
+        min: f32,
+        max: f32,
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
+        unit: Option<&'static str>,
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DistanceOutput {
+    pub value: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+    pub latent: bool,
 }
 
 #[derive(Clone,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantityOutput {
+    pub value: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "::serialization::deserialize_unit"))]
+    pub unit: Option<&'static str>,
+    pub family: QuantityFamily,
+    pub precision: Precision,
+    pub product: Option<String>,
+}
+
+#[derive(Clone,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DurationOutput {
     pub period: Period, 
     pub precision: Precision,
 }
 
+/// A repeated time. Occurrences are `interval` periods of `frequency` apart, starting at
+/// `start` when the text gives a time ("every monday at 3pm") and ending with `until`.
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RecurrenceOutput {
+    pub frequency: Grain,
+    pub interval: i64,
+    /// Number of occurrences per period, 2 for "twice a week"
+    pub times: Option<i64>,
+    /// First occurrence
+    pub start: Option<Moment<Tz>>,
+    /// Grain of each occurrence
+    pub grain: Grain,
+    pub until: Option<Moment<Tz>>,
+}
+
+impl RecurrenceOutput {
+    /// Renders the recurrence as an RFC 5545 RRULE, like `FREQ=WEEKLY;BYDAY=TU;BYHOUR=15`, to be
+    /// used with `start` as DTSTART. The number of occurrences per period has no RRULE
+    /// equivalent and is not rendered.
+    pub fn to_rrule(&self) -> String {
+        let (freq, interval) = match self.frequency {
+            Grain::Year => ("YEARLY", self.interval),
+            Grain::Quarter => ("MONTHLY", 3 * self.interval),
+            Grain::Month => ("MONTHLY", self.interval),
+            Grain::Week => ("WEEKLY", self.interval),
+            Grain::Day => ("DAILY", self.interval),
+            Grain::Hour => ("HOURLY", self.interval),
+            Grain::Minute => ("MINUTELY", self.interval),
+            Grain::Second => ("SECONDLY", self.interval),
+        };
+        let mut parts = vec![format!("FREQ={}", freq)];
+        if interval > 1 {
+            parts.push(format!("INTERVAL={}", interval));
+        }
+        if let Some(start) = self.start {
+            // Grains are ordered from the coarsest to the finest
+            let by = |grain: Grain| self.frequency < grain && self.grain >= grain;
+            if by(Grain::Month) && self.frequency == Grain::Year {
+                parts.push(format!("BYMONTH={}", start.month()));
+            }
+            if by(Grain::Day) && self.frequency == Grain::Week {
+                let day = match start.weekday() {
+                    Weekday::Mon => "MO",
+                    Weekday::Tue => "TU",
+                    Weekday::Wed => "WE",
+                    Weekday::Thu => "TH",
+                    Weekday::Fri => "FR",
+                    Weekday::Sat => "SA",
+                    Weekday::Sun => "SU",
+                };
+                parts.push(format!("BYDAY={}", day));
+            } else if by(Grain::Day) && self.frequency != Grain::Week {
+                parts.push(format!("BYMONTHDAY={}", start.day()));
+            }
+            if by(Grain::Hour) {
+                parts.push(format!("BYHOUR={}", start.hour()));
+            }
+            if by(Grain::Minute) {
+                parts.push(format!("BYMINUTE={}", start.minute()));
+            }
+            if by(Grain::Second) {
+                parts.push(format!("BYSECOND={}", start.second()));
+            }
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("UNTIL={}", until.0.naive_utc().format("%Y%m%dT%H%M%SZ")));
+        }
+        parts.join(";")
+    }
+}
+
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
+variant_converters!(Output, NumberRange, NumberRangeOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
 variant_converters!(Output, Ordinal, OrdinalOutput);
 variant_converters!(Output, Time, TimeOutput);
 variant_converters!(Output, TimeInterval, TimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
+variant_converters!(Output, AmountOfMoneyInterval, AmountOfMoneyIntervalOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
-variant_converters!(Output, Duration, DurationOutput);
\ No newline at end of file
+variant_converters!(Output, TemperatureInterval, TemperatureIntervalOutput);
+variant_converters!(Output, Distance, DistanceOutput);
+variant_converters!(Output, Quantity, QuantityOutput);
+variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, Recurrence, RecurrenceOutput);
+
+/// Byte or char range of a match, end excluded.
+#[derive(Clone,Copy,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OutputRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Resolved match detached from the parser, meant to be exchanged with other programs.
+#[derive(Clone,PartialEq,Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OutputMatch {
+    pub byte_range: OutputRange,
+    pub char_range: OutputRange,
+    pub kind: OutputKind,
+    pub value: Output,
+    pub probalog: f32,
+    pub latent: bool,
+}
+
+impl From<ParserMatch<Output>> for OutputMatch {
+    fn from(m: ParserMatch<Output>) -> OutputMatch {
+        OutputMatch {
+            byte_range: OutputRange { start: m.byte_range.0, end: m.byte_range.1 },
+            char_range: OutputRange { start: m.char_range.0, end: m.char_range.1 },
+            kind: m.value.kind(),
+            value: m.value,
+            probalog: m.probalog,
+            latent: m.latent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_rrule() {
+        let output = RecurrenceOutput {
+            frequency: Grain::Week,
+            interval: 2,
+            times: None,
+            start: Some(Moment(Tz::east(3600).ymd(2013, 2, 18).and_hms(15, 0, 0))),
+            grain: Grain::Hour,
+            until: Some(Moment(Tz::east(3600).ymd(2013, 7, 1).and_hms(0, 0, 0))),
+        };
+        assert_eq!("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;BYHOUR=15;UNTIL=20130630T230000Z", output.to_rrule());
+    }
+}
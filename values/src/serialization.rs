@@ -0,0 +1,166 @@
+//! Serde support for the outputs.
+//!
+//! `Output` is serialized as `{"type": <variant>, "value": <payload>}`, moments as RFC 3339
+//! strings carrying their UTC offset and periods as lists of `{"grain", "quantity"}`
+//! components.
+
+use serde::{de, Deserialize, Deserializer};
+
+/// Units produced by the grammars. Units are `&'static str` in the outputs, so deserialized
+/// units have to be one of them.
+pub static KNOWN_UNITS: &'static [&'static str] = &[
+    "$", "€", "£", "¥", "฿",
+    "AED", "AUD", "CAD", "CHF", "CNY", "EUR", "GBP", "HKD", "INR", "JPY", "KRW", "PTS", "RUB", "USD",
+    "cent",
+    "celsius", "fahrenheit", "kelvin", "degree",
+    "kilometre", "metre", "centimetre", "millimetre", "mile", "yard", "foot", "inch",
+    "kilogram", "gram", "milligram", "pound", "ounce",
+    "litre", "centilitre", "millilitre", "cup", "tablespoon", "teaspoon", "pint", "gallon",
+];
+
+pub fn deserialize_unit<'de, D>(deserializer: D) -> Result<Option<&'static str>, D::Error>
+    where D: Deserializer<'de>
+{
+    let unit: Option<String> = Option::deserialize(deserializer)?;
+    match unit {
+        None => Ok(None),
+        Some(unit) => {
+            KNOWN_UNITS.iter()
+                .find(|known| **known == unit)
+                .map(|known| Some(*known))
+                .ok_or_else(|| de::Error::custom(format!("Unknown unit {}", unit)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use moment::*;
+    use dimension::{Precision, QuantityFamily};
+    use output::*;
+
+    fn round_trip(output: Output) {
+        let json = serde_json::to_string(&output).unwrap();
+        let deserialized: Output = serde_json::from_str(&json).unwrap();
+        assert_eq!(output, deserialized);
+    }
+
+    #[test]
+    fn test_integer_schema() {
+        let json = serde_json::to_value(&Output::Integer(IntegerOutput(21))).unwrap();
+        assert_eq!(json!({"type": "Integer", "value": 21}), json);
+        round_trip(Output::Integer(IntegerOutput(21)));
+        round_trip(Output::Float(FloatOutput(3.5)));
+        round_trip(Output::Ordinal(OrdinalOutput(2)));
+        round_trip(Output::Percentage(PercentageOutput(12.0)));
+        round_trip(Output::NumberRange(NumberRangeOutput {
+            min: Some(3.0),
+            max: None,
+            min_inclusive: true,
+            max_inclusive: false,
+        }));
+    }
+
+    #[test]
+    fn test_time_schema() {
+        let moment = Moment(Tz::east(3600).ymd(2013, 2, 12).and_hms(4, 30, 0));
+        let output = Output::Time(TimeOutput {
+            moment: moment,
+            grain: Grain::Minute,
+            precision: Precision::Exact,
+            latent: false,
+        });
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json!({
+            "type": "Time",
+            "value": {
+                "moment": "2013-02-12T04:30:00+01:00",
+                "grain": "Minute",
+                "precision": "Exact",
+                "latent": false
+            }
+        }), json);
+        round_trip(output);
+        round_trip(Output::TimeInterval(TimeIntervalOutput::Between {
+            start: moment,
+            end: moment + PeriodComp::days(2),
+            precision: Precision::Approximate,
+            latent: true,
+        }));
+    }
+
+    #[test]
+    fn test_units_and_periods() {
+        round_trip(Output::AmountOfMoney(AmountOfMoneyOutput {
+            value: 12.5,
+            precision: Precision::Exact,
+            unit: Some("EUR"),
+        }));
+        round_trip(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::Before(AmountOfMoneyOutput {
+            value: 50.0,
+            precision: Precision::Exact,
+            unit: Some("$"),
+        })));
+        round_trip(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::AtLeast(AmountOfMoneyOutput {
+            value: 50.0,
+            precision: Precision::Exact,
+            unit: Some("$"),
+        })));
+        round_trip(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::Between {
+            min: 20.0,
+            max: 30.0,
+            precision: Precision::Exact,
+            unit: Some("EUR"),
+        }));
+        round_trip(Output::Temperature(TemperatureOutput {
+            value: 21.0,
+            unit: None,
+            latent: true,
+            delta: false,
+        }));
+        round_trip(Output::TemperatureInterval(TemperatureIntervalOutput::AtLeast(TemperatureOutput {
+            value: 20.0,
+            unit: Some("degree"),
+            latent: false,
+            delta: false,
+        })));
+        round_trip(Output::TemperatureInterval(TemperatureIntervalOutput::Between {
+            min: 18.0,
+            max: 21.0,
+            unit: Some("degree"),
+        }));
+        round_trip(Output::Distance(DistanceOutput {
+            value: 6.5,
+            unit: Some("kilometre"),
+            precision: Precision::Approximate,
+            latent: false,
+        }));
+        round_trip(Output::Quantity(QuantityOutput {
+            value: 0.5,
+            unit: Some("cup"),
+            family: QuantityFamily::Volume,
+            precision: Precision::Exact,
+            product: Some("flour".to_string()),
+        }));
+        round_trip(Output::Duration(DurationOutput {
+            period: Period::from(PeriodComp::hours(3)) + PeriodComp::minutes(15),
+            precision: Precision::Exact,
+        }));
+        let unknown = r#"{"type": "AmountOfMoney", "value": {"value": 1.0, "precision": "Exact", "unit": "foo"}}"#;
+        assert!(serde_json::from_str::<Output>(unknown).is_err());
+    }
+
+    #[test]
+    fn test_recurrence() {
+        let output = RecurrenceOutput {
+            frequency: Grain::Week,
+            interval: 2,
+            times: None,
+            start: Some(Moment(Tz::east(3600).ymd(2013, 2, 18).and_hms(15, 0, 0))),
+            grain: Grain::Hour,
+            until: Some(Moment(Tz::east(3600).ymd(2013, 7, 1).and_hms(0, 0, 0))),
+        };
+        round_trip(Output::Recurrence(output));
+    }
+}